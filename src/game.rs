@@ -1,20 +1,54 @@
 pub mod board;
 pub mod board_position;
+pub mod chess_move;
+pub mod clock;
+pub mod fen;
+pub mod game_status;
+pub mod move_history;
+pub mod pgn;
+pub mod position_key;
+pub mod render;
+pub mod san;
 
 use crate::{
     piece::{
         bishop::Bishop, king::King, knight::Knight, pawn::Pawn, queen::Queen, rook::Rook,
-        ChessPiece, ChessPieceTrait,
+        ChessPiece, ChessPieceTrait, PieceMoveData,
     },
     player::Player,
 };
 use board::{initialize_empty_board, ChessBoard, ChessBoardData};
 use board_position::{BoardPosition, CandidateBoardPosition};
-use std::collections::HashMap;
+use chess_move::{CastlingSide, LegalMove, Move, MoveError, MoveFlags, PromotionPiece};
+use clock::{ChessClock, TimeControl};
+use game_status::{DrawReason, GameResult, GameStatus};
+use move_history::MoveHistoryEntry;
+use position_key::PositionKey;
+use std::{collections::HashMap, time::Instant};
 
+// The fifty-move and seventy-five-move rules, counted in moves by either player
+const FIFTY_MOVE_RULE_HALFMOVES: u32 = 100;
+const SEVENTY_FIVE_MOVE_RULE_HALFMOVES: u32 = 150;
+
+#[derive(Clone)]
 pub struct ChessGame {
     pub board_data: ChessBoardData,
     pub turn: u32, // since white starts first, if turn % 2 == 0 means white's turn, otherwise black's turn
+    pub status: GameStatus,
+    pub move_history: Vec<MoveHistoryEntry>,
+    // Moves taken back with `undo_move`, most recently undone last, which `redo_move` replays.
+    // They keep their whole history entry, so a redone move gets its comment back
+    pub undone_moves: Vec<MoveHistoryEntry>,
+    // The position skipped over by a pawn that moved two positions forward on the previous turn
+    pub en_passant_target: Option<BoardPosition>,
+    // Moves by either player since the last capture or pawn move
+    pub halfmove_clock: u32,
+    // Every position reached so far, including the current one, for detecting repetitions
+    pub position_history: Vec<PositionKey>,
+    // The position the game was set up from, which the moves in `move_history` are played from
+    pub starting_fen: String,
+    // The players' clocks, in a timed game, in which moves can not be undone
+    pub clock: Option<ChessClock>,
 }
 
 impl ChessGame {
@@ -42,7 +76,8 @@ impl ChessGame {
                             row_index: row_index as i32,
                             column_index: column_index as i32
                         }
-                        .validate_candidate_position_and_unwrap(&board)
+                        .validate_candidate_position_and_unwrap(board),
+                        has_been_moved: false
                     }),
                     1 | 6 => ChessPiece::Knight(Knight {
                         player,
@@ -50,7 +85,7 @@ impl ChessGame {
                             row_index: row_index as i32,
                             column_index: column_index as i32
                         }
-                        .validate_candidate_position_and_unwrap(&board)
+                        .validate_candidate_position_and_unwrap(board)
                     }),
                     2 | 5 => ChessPiece::Bishop(Bishop {
                         player,
@@ -58,7 +93,7 @@ impl ChessGame {
                             row_index: row_index as i32,
                             column_index: column_index as i32
                         }
-                        .validate_candidate_position_and_unwrap(&board)
+                        .validate_candidate_position_and_unwrap(board)
                     }),
                     3 => ChessPiece::Queen(Queen {
                         player,
@@ -66,7 +101,7 @@ impl ChessGame {
                             row_index: row_index as i32,
                             column_index: column_index as i32
                         }
-                        .validate_candidate_position_and_unwrap(&board)
+                        .validate_candidate_position_and_unwrap(board)
                     }),
                     4 => ChessPiece::King(King {
                         player,
@@ -74,7 +109,8 @@ impl ChessGame {
                             row_index: row_index as i32,
                             column_index: column_index as i32
                         }
-                        .validate_candidate_position_and_unwrap(&board)
+                        .validate_candidate_position_and_unwrap(board),
+                        has_been_moved: false
                     }),
                     _ => panic!("Invalid {column_index}"),
                 },
@@ -84,7 +120,7 @@ impl ChessGame {
                         row_index: row_index as i32,
                         column_index: column_index as i32
                     }
-                    .validate_candidate_position_and_unwrap(&board),
+                    .validate_candidate_position_and_unwrap(board),
                     has_been_moved: false
                 }),
                 _ => panic!("Given row_index: {row_index}. This should not be evaluated as a starting row index.")
@@ -93,12 +129,10 @@ impl ChessGame {
 
         // Chess board should be an 8x8 2D array
         let mut board: ChessBoard = initialize_empty_board();
-        let mut black_pieces: HashMap<BoardPosition, ChessPiece> = HashMap::new();
-        let mut white_pieces: HashMap<BoardPosition, ChessPiece> = HashMap::new();
 
         for row_index in 0..=7 {
             for column_index in 0..=7 {
-                if row_index >= 2 && row_index <= 5 {
+                if (2..=5).contains(&row_index) {
                     // Non-occupied space (row indices 2, 3, 4, 5)
                     // Since we initialize pieces to None by default, we can continue
                     continue;
@@ -106,87 +140,3522 @@ impl ChessGame {
                     let piece: ChessPiece =
                         get_piece_by_initial_position(row_index, column_index, &board);
                     board[row_index][column_index] = Some(piece);
+                }
+            }
+        }
 
-                    let player = get_player_by_initial_row(row_index);
-                    let piece: ChessPiece =
-                        get_piece_by_initial_position(row_index, column_index, &board);
-                    match player {
-                        Player::Black => black_pieces.insert(
-                            CandidateBoardPosition {
-                                row_index: row_index as i32,
-                                column_index: column_index as i32,
-                            }
-                            .validate_candidate_position_and_unwrap(&board),
-                            piece,
-                        ),
-                        Player::White => white_pieces.insert(
-                            CandidateBoardPosition {
-                                row_index: row_index as i32,
-                                column_index: column_index as i32,
-                            }
-                            .validate_candidate_position_and_unwrap(&board),
-                            piece,
-                        ),
-                    };
-                }
-            }
-        }
-
-        Self {
-            board_data: ChessBoardData {
-                board,
-                black_pieces,
-                white_pieces,
+        Self::from_board(board, 0)
+    }
+
+    pub fn from_board(board: ChessBoard, turn: u32) -> Self {
+        let mut game: ChessGame = Self {
+            board_data: ChessBoardData::new(board),
+            turn,
+            status: GameStatus::Ongoing,
+            move_history: vec![],
+            undone_moves: vec![],
+            en_passant_target: None,
+            halfmove_clock: 0,
+            position_history: vec![],
+            starting_fen: String::new(),
+            clock: None,
+        };
+        game.position_history.push(game.get_position_key());
+        game.starting_fen = game.to_fen();
+        game
+    }
+
+    pub fn get_current_player(&self) -> Player {
+        if self.turn.is_multiple_of(2) {
+            Player::White
+        } else {
+            Player::Black
+        }
+    }
+
+    /*
+        Returns the fully legal moves and captures for every piece belonging to `player` that
+        has at least one, keyed by the position of the piece.
+
+        `valid_moves_and_captures` on each piece only knows how that piece moves, so it will
+        happily return moves that leave the player's own king in check (moving a pinned piece,
+        or a king stepping onto an attacked position). Each of those pseudo-legal moves is played
+        out on a copy of the board, and only kept if the king is safe afterwards.
+    */
+    pub fn legal_moves(&self, player: &Player) -> HashMap<BoardPosition, PieceMoveData> {
+        let mut legal_moves: HashMap<BoardPosition, PieceMoveData> = HashMap::new();
+
+        for (position, piece) in self.board_data.get_player_pieces(player).iter() {
+            let PieceMoveData {
+                valid_moves,
+                valid_captures,
+            } = match piece {
+                ChessPiece::Pawn(pawn) => pawn.valid_moves_and_captures_with_en_passant(
+                    &self.board_data.board,
+                    self.en_passant_target.as_ref(),
+                ),
+                _ => piece.valid_moves_and_captures(&self.board_data.board),
+            };
+            let piece_move_data = PieceMoveData {
+                valid_moves: valid_moves
+                    .into_iter()
+                    .filter(|to| self.is_king_safe_after_move(player, position, to))
+                    .collect(),
+                valid_captures: valid_captures
+                    .into_iter()
+                    .filter(|to| self.is_king_safe_after_move(player, position, to))
+                    .collect(),
+            };
+
+            if !piece_move_data.valid_moves.is_empty() || !piece_move_data.valid_captures.is_empty()
+            {
+                legal_moves.insert(*position, piece_move_data);
+            }
+        }
+
+        legal_moves
+    }
+
+    /*
+        Returns every legal move for `player` as a flat list. Unlike `legal_moves`, a pawn
+        push or capture onto the last row is expanded into one move per promotion piece.
+    */
+    pub fn legal_move_list(&self, player: &Player) -> Vec<LegalMove> {
+        let mut legal_move_list: Vec<LegalMove> = vec![];
+
+        for (from, piece_move_data) in self.legal_moves(player).iter() {
+            for to in piece_move_data
+                .valid_moves
+                .iter()
+                .chain(piece_move_data.valid_captures.iter())
+            {
+                if self.is_promotion_move(from, to) {
+                    legal_move_list.extend(PromotionPiece::ALL.iter().map(|promotion| LegalMove {
+                        from: *from,
+                        to: *to,
+                        promotion: Some(*promotion),
+                    }));
+                } else {
+                    legal_move_list.push(LegalMove {
+                        from: *from,
+                        to: *to,
+                        promotion: None,
+                    });
+                }
+            }
+        }
+
+        legal_move_list
+    }
+
+    // Returns true if moving the piece on `from` to `to` is a pawn reaching its last row
+    pub fn is_promotion_move(&self, from: &BoardPosition, to: &BoardPosition) -> bool {
+        matches!(
+            &self.board_data.board[from.get_row_index()][from.get_column_index()],
+            Some(ChessPiece::Pawn(pawn)) if to.get_row_index() == pawn.get_promotion_row_index()
+        )
+    }
+
+    /*
+        Moves the piece on `from` to `to` for the player whose turn it is, provided it is one of
+        that player's legal moves. Castling is made by moving the king two positions towards
+        the rook, which moves the rook alongside it.
+
+        After the move the turn passes to the opposing player and the game status is updated.
+        Making a new move discards any moves that were undone, as they can no longer be redone.
+    */
+    pub fn make_move(
+        &mut self,
+        from: &BoardPosition,
+        to: &BoardPosition,
+    ) -> Result<Move, MoveError> {
+        self.make_move_with_promotion(from, to, None)
+    }
+
+    // Same as `make_move`, but also takes the piece to promote to when a pawn reaches its last row
+    pub fn make_move_with_promotion(
+        &mut self,
+        from: &BoardPosition,
+        to: &BoardPosition,
+        promotion: Option<PromotionPiece>,
+    ) -> Result<Move, MoveError> {
+        let now: Instant = Instant::now();
+        // A move made after the player's time ran out is too late to count
+        self.check_flag_fall_at(now);
+        let chess_move: Move = self.play_move(from, to, promotion)?;
+        self.undone_moves.clear();
+        self.press_clock(now);
+        Ok(chess_move)
+    }
+
+    fn play_move(
+        &mut self,
+        from: &BoardPosition,
+        to: &BoardPosition,
+        promotion: Option<PromotionPiece>,
+    ) -> Result<Move, MoveError> {
+        if self.status.is_finished() {
+            return Err(MoveError::GameIsFinished);
+        }
+
+        let player: Player = self.get_current_player();
+        let piece: ChessPiece = self.board_data.board[from.get_row_index()]
+            [from.get_column_index()]
+        .clone()
+        .ok_or(MoveError::NoPieceAtPosition(*from))?;
+        if piece.get_player() != &player {
+            return Err(MoveError::NotPlayersPiece {
+                position: *from,
+                player,
+            });
+        }
+
+        let legal_move: LegalMove = LegalMove {
+            from: *from,
+            to: *to,
+            promotion,
+        };
+        if !self.legal_move_list(&player).contains(&legal_move) {
+            if promotion.is_none() && self.is_promotion_move(from, to) {
+                return Err(MoveError::MissingPromotionPiece {
+                    from: *from,
+                    to: *to,
+                });
+            }
+            return Err(MoveError::IllegalMove {
+                from: *from,
+                to: *to,
+            });
+        }
+
+        let history_entry_board_data: ChessBoardData = self.board_data.clone();
+        let history_entry_turn: u32 = self.turn;
+        let history_entry_status: GameStatus = self.status;
+        let history_entry_en_passant_target: Option<BoardPosition> = self.en_passant_target;
+        let history_entry_halfmove_clock: u32 = self.halfmove_clock;
+
+        let en_passant_captured_position: Option<BoardPosition> =
+            self.get_en_passant_captured_position(from, to);
+        let mut captured_piece: Option<ChessPiece> = self.board_data.move_piece(from, to);
+        if let Some(en_passant_captured_position) = &en_passant_captured_position {
+            captured_piece = self.board_data.remove_piece(en_passant_captured_position);
+        }
+        if let Some(promotion) = promotion {
+            self.board_data
+                .place_piece(promotion.to_chess_piece(player, *to));
+        }
+
+        let castling: Option<CastlingSide> = match piece {
+            ChessPiece::King(_) if to.get_column_index() == from.get_column_index() + 2 => {
+                Some(CastlingSide::Kingside)
+            }
+            ChessPiece::King(_) if to.get_column_index() + 2 == from.get_column_index() => {
+                Some(CastlingSide::Queenside)
+            }
+            _ => None,
+        };
+        if let Some(castling_side) = castling {
+            let (rook_from_column_index, rook_to_column_index): (i32, i32) = match castling_side {
+                CastlingSide::Kingside => (7, 5),
+                CastlingSide::Queenside => (0, 3),
+            };
+            let rook_from: BoardPosition = CandidateBoardPosition {
+                row_index: from.get_row_index() as i32,
+                column_index: rook_from_column_index,
+            }
+            .validate_candidate_position_and_unwrap(&self.board_data.board);
+            let rook_to: BoardPosition = CandidateBoardPosition {
+                row_index: from.get_row_index() as i32,
+                column_index: rook_to_column_index,
+            }
+            .validate_candidate_position_and_unwrap(&self.board_data.board);
+            self.board_data.move_piece(&rook_from, &rook_to);
+        }
+
+        // A pawn moving two positions forward can be captured en passant on the next turn only
+        self.en_passant_target = match piece {
+            ChessPiece::Pawn(_) if from.get_row_index().abs_diff(to.get_row_index()) == 2 => Some(
+                CandidateBoardPosition {
+                    row_index: (from.get_row_index() + to.get_row_index()) as i32 / 2,
+                    column_index: from.get_column_index() as i32,
+                }
+                .validate_candidate_position_and_unwrap(&self.board_data.board),
+            ),
+            _ => None,
+        };
+
+        self.halfmove_clock = match (&piece, &captured_piece) {
+            (ChessPiece::Pawn(_), _) | (_, Some(_)) => 0,
+            _ => self.halfmove_clock + 1,
+        };
+        self.turn += 1;
+        self.position_history.push(self.get_position_key());
+        self.update_status();
+
+        let chess_move: Move = Move {
+            piece,
+            from: *from,
+            to: *to,
+            captured_piece,
+            flags: MoveFlags {
+                castling,
+                promotion,
+                en_passant: en_passant_captured_position.is_some(),
+                is_check: self.board_data.is_in_check(&player.get_opponent()),
+                is_checkmate: matches!(
+                    self.status,
+                    GameStatus::Finished(GameResult::Checkmate { .. })
+                ),
             },
-            turn: 0,
-        }
-    }
-
-    pub fn display_board(&self) {
-        println!();
-        println!("    {}[Black]{}", " ".repeat(20), " ".repeat(20));
-        println!();
-        println!(
-            "{}a{}b{}c{}d{}e{}f{}g{}h",
-            " ".repeat(7),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-        );
-        println!("    {}", "-".repeat(49));
-        for (row_index, row) in self.board_data.board.iter().enumerate() {
-            print!(" {}  |", self.board_data.board.len() - row_index);
-            for (column_index, piece) in row.iter().enumerate() {
-                print!(" ");
-                match piece {
-                    Some(piece) => piece.display_piece_on_board(),
-                    None => print!("   "),
-                }
-                if column_index < row.len() - 1 {
-                    print!(" |");
-                }
-            }
-            print!(" |  {}", self.board_data.board.len() - row_index);
-            println!();
-            println!("    {}", "-".repeat(49));
-        }
-        println!(
-            "{}a{}b{}c{}d{}e{}f{}g{}h",
-            " ".repeat(7),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
-            " ".repeat(5),
+        };
+        self.move_history.push(MoveHistoryEntry {
+            chess_move: chess_move.clone(),
+            board_data: history_entry_board_data,
+            turn: history_entry_turn,
+            status: history_entry_status,
+            en_passant_target: history_entry_en_passant_target,
+            halfmove_clock: history_entry_halfmove_clock,
+            comment: None,
+            remaining_time: None,
+            recorded_result: None,
+        });
+
+        Ok(chess_move)
+    }
+
+    /*
+        Takes back the most recent move, restoring the game exactly as it was before it was made.
+        Moves can not be taken back against the clock, as the time spent on them is gone.
+    */
+    pub fn undo_move(&mut self) -> Option<Move> {
+        if self.clock.is_some() {
+            return None;
+        }
+        let history_entry: MoveHistoryEntry = self.move_history.pop()?;
+        self.board_data = history_entry.board_data.clone();
+        self.turn = history_entry.turn;
+        self.status = history_entry.status;
+        self.en_passant_target = history_entry.en_passant_target;
+        self.halfmove_clock = history_entry.halfmove_clock;
+        self.position_history.pop();
+        let chess_move: Move = history_entry.chess_move.clone();
+        self.undone_moves.push(history_entry);
+        Some(chess_move)
+    }
+
+    /*
+        Replays the most recently undone move, if it can still be played, along with the comment,
+        the time on the clock and the recorded result that were kept for it.
+    */
+    pub fn redo_move(&mut self) -> Option<Move> {
+        if self.clock.is_some() {
+            return None;
+        }
+        let undone_entry: MoveHistoryEntry = self.undone_moves.pop()?;
+        let undone_move: &Move = &undone_entry.chess_move;
+        match self.play_move(
+            &undone_move.from,
+            &undone_move.to,
+            undone_move.flags.promotion,
+        ) {
+            Ok(chess_move) => {
+                if let Some(history_entry) = self.move_history.last_mut() {
+                    history_entry.comment = undone_entry.comment;
+                    history_entry.remaining_time = undone_entry.remaining_time;
+                    history_entry.recorded_result = undone_entry.recorded_result;
+                }
+                if let Some(result) = undone_entry.recorded_result {
+                    self.status = GameStatus::Finished(result);
+                }
+                Some(chess_move)
+            }
+            Err(_) => {
+                self.undone_moves.push(undone_entry);
+                None
+            }
+        }
+    }
+
+    /*
+        Re-evaluates the status of the game for the player whose turn it is. Once a game has
+        finished (including by resignation or agreement), its status no longer changes.
+
+        A player with no legal moves has either been checkmated (if they are in check) or
+        stalemated (if they are not). Otherwise the game is drawn automatically by insufficient
+        material, fivefold repetition or the seventy-five-move rule, and the player may claim a
+        draw by threefold repetition or the fifty-move rule.
+    */
+    pub fn update_status(&mut self) -> GameStatus {
+        if self.status.is_finished() {
+            return self.status;
+        }
+
+        let player: Player = self.get_current_player();
+        let repetition_count: usize = self.get_repetition_count();
+        self.status = if self.legal_moves(&player).is_empty() {
+            if self.board_data.is_in_check(&player) {
+                GameStatus::Finished(GameResult::Checkmate {
+                    winner: player.get_opponent(),
+                })
+            } else {
+                GameStatus::Finished(GameResult::Stalemate)
+            }
+        } else if self.board_data.has_insufficient_material() {
+            GameStatus::Finished(GameResult::Draw(DrawReason::InsufficientMaterial))
+        } else if repetition_count >= 5 {
+            GameStatus::Finished(GameResult::Draw(DrawReason::FivefoldRepetition))
+        } else if self.halfmove_clock >= SEVENTY_FIVE_MOVE_RULE_HALFMOVES {
+            GameStatus::Finished(GameResult::Draw(DrawReason::SeventyFiveMoveRule))
+        } else if repetition_count >= 3 {
+            GameStatus::DrawClaimable(DrawReason::ThreefoldRepetition)
+        } else if self.halfmove_clock >= FIFTY_MOVE_RULE_HALFMOVES {
+            GameStatus::DrawClaimable(DrawReason::FiftyMoveRule)
+        } else {
+            GameStatus::Ongoing
+        };
+
+        self.status
+    }
+
+    // Attaches a comment to the most recent move, replacing any earlier comment on it
+    pub fn comment_on_last_move(&mut self, comment: String) {
+        if let Some(history_entry) = self.move_history.last_mut() {
+            history_entry.comment = Some(comment);
+        }
+    }
+
+    pub fn resign(&mut self, player: &Player) {
+        self.finish(
+            GameResult::Resignation {
+                winner: player.get_opponent(),
+            },
+            Instant::now(),
         );
-        println!();
-        println!("    {}(White){}", " ".repeat(20), " ".repeat(20));
-        println!();
+    }
+
+    pub fn agree_draw(&mut self) {
+        self.finish(GameResult::Draw(DrawReason::Agreement), Instant::now());
+    }
+
+    // Ends the game as a draw if the player to move is currently able to claim one
+    pub fn claim_draw(&mut self) {
+        if let GameStatus::DrawClaimable(reason) = self.status {
+            self.finish(GameResult::Draw(reason), Instant::now());
+        }
+    }
+
+    /*
+        Ends the game with a result from a game record, which the position need not show. The
+        result is kept with the last move, so undoing that move reopens the game and redoing it
+        ends the game again.
+    */
+    pub fn record_result(&mut self, result: GameResult) {
+        if let Some(history_entry) = self.move_history.last_mut() {
+            history_entry.recorded_result = Some(result);
+        }
+        self.finish(result, Instant::now());
+    }
+
+    /*
+        Ends the game other than by a move, e.g. by resignation or on time. No more moves can be
+        made, so the moves that were undone can no longer be redone either.
+    */
+    fn finish(&mut self, result: GameResult, now: Instant) {
+        self.status = GameStatus::Finished(result);
+        self.undone_moves.clear();
+        self.stop_clock(now);
+    }
+
+    // Plays the rest of the game against the clock, starting with the player to move
+    pub fn start_clock(&mut self, time_control: TimeControl) {
+        let mut clock: ChessClock = ChessClock::new(time_control);
+        clock.start(self.get_current_player(), Instant::now());
+        self.clock = Some(clock);
+    }
+
+    // Ends the game if the player to move has run out of time
+    pub fn check_flag_fall(&mut self) -> GameStatus {
+        self.check_flag_fall_at(Instant::now())
+    }
+
+    /*
+        A player who runs out of time loses, unless their opponent has no way left to checkmate
+        them, in which case the game is a draw.
+    */
+    fn check_flag_fall_at(&mut self, now: Instant) -> GameStatus {
+        if self.status.is_finished() {
+            return self.status;
+        }
+        let Some(flagged_player) = self
+            .clock
+            .as_ref()
+            .and_then(|clock| clock.get_flagged_player(now))
+        else {
+            return self.status;
+        };
+
+        let opponent: Player = flagged_player.get_opponent();
+        let result: GameResult = if self.board_data.has_mating_material(&opponent) {
+            GameResult::Timeout { winner: opponent }
+        } else {
+            GameResult::Draw(DrawReason::TimeoutVsInsufficientMaterial)
+        };
+        self.finish(result, now);
+        self.status
+    }
+
+    // Ends the move on the clock, recording the mover's remaining time alongside the move
+    fn press_clock(&mut self, now: Instant) {
+        let Some(clock) = self.clock.as_mut() else {
+            return;
+        };
+        let remaining_time = clock.press(now);
+        if let Some(history_entry) = self.move_history.last_mut() {
+            history_entry.remaining_time = remaining_time;
+        }
+        if self.status.is_finished() {
+            self.stop_clock(now);
+        }
+    }
+
+    fn stop_clock(&mut self, now: Instant) {
+        if let Some(clock) = self.clock.as_mut() {
+            clock.stop(now);
+        }
+    }
+
+    /*
+        The en passant target only counts towards the position if the player to move can
+        actually capture en passant, so a double pawn push that nothing can capture does not
+        stop the position from repeating.
+    */
+    fn get_position_key(&self) -> PositionKey {
+        let player: Player = self.get_current_player();
+        let en_passant_target: Option<BoardPosition> = self.en_passant_target.filter(|target| {
+            self.legal_moves(&player)
+                .values()
+                .any(|piece_move_data| piece_move_data.valid_captures.contains(target))
+        });
+        PositionKey::new(&self.board_data, player, en_passant_target)
+    }
+
+    // How many times the current position has been reached, including now
+    fn get_repetition_count(&self) -> usize {
+        self.position_history
+            .last()
+            .map_or(0, |current_position_key| {
+                self.position_history
+                    .iter()
+                    .filter(|position_key| *position_key == current_position_key)
+                    .count()
+            })
+    }
+
+    fn is_king_safe_after_move(
+        &self,
+        player: &Player,
+        from: &BoardPosition,
+        to: &BoardPosition,
+    ) -> bool {
+        let mut board_data: ChessBoardData = self.board_data.clone();
+        if let Some(en_passant_captured_position) = self.get_en_passant_captured_position(from, to)
+        {
+            board_data.remove_piece(&en_passant_captured_position);
+        }
+        board_data.move_piece(from, to);
+        !board_data.is_in_check(player)
+    }
+
+    /*
+        If moving the piece on `from` to `to` is an en passant capture, returns the position of the
+        pawn being captured. Unlike any other capture, it is not on the position being moved to,
+        but beside the capturing pawn (on the row it started from).
+    */
+    fn get_en_passant_captured_position(
+        &self,
+        from: &BoardPosition,
+        to: &BoardPosition,
+    ) -> Option<BoardPosition> {
+        match &self.board_data.board[from.get_row_index()][from.get_column_index()] {
+            Some(ChessPiece::Pawn(_)) if self.en_passant_target.as_ref() == Some(to) => Some(
+                CandidateBoardPosition {
+                    row_index: from.get_row_index() as i32,
+                    column_index: to.get_column_index() as i32,
+                }
+                .validate_candidate_position_and_unwrap(&self.board_data.board),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_legal_moves {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+                ChessGame,
+            },
+            piece::{
+                bishop::Bishop, king::King, knight::Knight, pawn::Pawn, rook::Rook, ChessPiece,
+                PieceMoveData,
+            },
+            player::Player,
+        };
+
+        #[test]
+        fn test_starting_position_legal_moves() {
+            let game: ChessGame = ChessGame::new();
+            let legal_moves = game.legal_moves(&Player::White);
+            // Eight pawns and two knights can move, with two options each
+            assert_eq!(legal_moves.len(), 10);
+            assert_eq!(
+                legal_moves
+                    .values()
+                    .map(|piece_move_data| piece_move_data.valid_moves.len())
+                    .sum::<usize>(),
+                20
+            );
+        }
+
+        #[test]
+        fn test_piece_pinned_against_king_cannot_move() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][4] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[7][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 0);
+            assert!(!game.legal_moves(&Player::White).contains_key(
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board)
+            ));
+        }
+
+        #[test]
+        fn test_pinned_piece_can_move_along_the_pin() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 0);
+            assert_eq!(
+                game.legal_moves(&Player::White).get(
+                    &CandidateBoardPosition {
+                        row_index: 2,
+                        column_index: 4,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                ),
+                Some(&PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    ],
+                    valid_captures: vec![CandidateBoardPosition {
+                        row_index: 6,
+                        column_index: 4,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),],
+                })
+            );
+        }
+
+        #[test]
+        fn test_black_knight_pinned_diagonally_cannot_move() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][7] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][6] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[3][3] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 1);
+            assert!(!game.legal_moves(&Player::Black).contains_key(
+                &CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board)
+            ));
+            assert_eq!(
+                game.legal_moves(&Player::Black).get(
+                    &CandidateBoardPosition {
+                        row_index: 7,
+                        column_index: 7,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                ),
+                Some(&PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 7,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 7,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    ],
+                    valid_captures: vec![],
+                })
+            );
+        }
+
+        #[test]
+        fn test_king_cannot_move_into_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][1] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 0);
+            assert_eq!(
+                game.legal_moves(&Player::White).get(
+                    &CandidateBoardPosition {
+                        row_index: 0,
+                        column_index: 0,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                ),
+                Some(&PieceMoveData {
+                    valid_moves: vec![CandidateBoardPosition {
+                        row_index: 1,
+                        column_index: 0,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),],
+                    valid_captures: vec![],
+                })
+            );
+        }
+
+        #[test]
+        fn test_king_cannot_capture_a_defended_piece() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 0);
+            assert_eq!(
+                game.legal_moves(&Player::White).get(
+                    &CandidateBoardPosition {
+                        row_index: 0,
+                        column_index: 0,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                ),
+                Some(&PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    ],
+                    valid_captures: vec![],
+                })
+            );
+        }
+
+        #[test]
+        fn test_only_check_evasions_are_legal() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][2] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[1][0] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 0);
+            assert_eq!(game.legal_moves(&Player::White).len(), 2);
+            assert_eq!(
+                game.legal_moves(&Player::White).get(
+                    &CandidateBoardPosition {
+                        row_index: 2,
+                        column_index: 2,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                ),
+                Some(&PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    ],
+                    valid_captures: vec![],
+                })
+            );
+            assert_eq!(
+                game.legal_moves(&Player::White).get(
+                    &CandidateBoardPosition {
+                        row_index: 0,
+                        column_index: 4,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                ),
+                Some(&PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    ],
+                    valid_captures: vec![],
+                })
+            );
+        }
+
+        #[test]
+        fn test_checking_piece_can_be_blocked_or_captured() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][1] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[7][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 0);
+            assert_eq!(
+                game.legal_moves(&Player::White).get(
+                    &CandidateBoardPosition {
+                        row_index: 4,
+                        column_index: 1,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                ),
+                Some(&PieceMoveData {
+                    valid_moves: vec![CandidateBoardPosition {
+                        row_index: 1,
+                        column_index: 4,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)],
+                    valid_captures: vec![CandidateBoardPosition {
+                        row_index: 7,
+                        column_index: 4,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),],
+                })
+            );
+        }
+    }
+
+    mod test_update_status {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+                game_status::{DrawReason, GameResult, GameStatus},
+                ChessGame,
+            },
+            piece::{king::King, pawn::Pawn, queen::Queen, rook::Rook, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_starting_position_is_ongoing() {
+            let mut game: ChessGame = ChessGame::new();
+            assert_eq!(game.update_status(), GameStatus::Ongoing);
+        }
+
+        #[test]
+        fn test_back_rank_checkmate() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][6] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][5] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][6] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][7] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            assert_eq!(
+                game.update_status(),
+                GameStatus::Finished(GameResult::Checkmate {
+                    winner: Player::Black
+                })
+            );
+        }
+
+        #[test]
+        fn test_check_that_can_be_escaped_is_ongoing() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][6] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][5] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][6] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            assert_eq!(game.update_status(), GameStatus::Ongoing);
+        }
+
+        #[test]
+        fn test_stalemate() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][7] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[5][6] = Some(ChessPiece::Queen(Queen {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[0][0] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 1);
+            assert_eq!(
+                game.update_status(),
+                GameStatus::Finished(GameResult::Stalemate)
+            );
+        }
+
+        #[test]
+        fn test_stalemate_is_only_evaluated_for_the_player_to_move() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][7] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[5][6] = Some(ChessPiece::Queen(Queen {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[0][0] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            assert_eq!(game.update_status(), GameStatus::Ongoing);
+        }
+
+        #[test]
+        fn test_resignation() {
+            let mut game: ChessGame = ChessGame::new();
+            game.resign(&Player::White);
+            assert_eq!(
+                game.update_status(),
+                GameStatus::Finished(GameResult::Resignation {
+                    winner: Player::Black
+                })
+            );
+        }
+
+        #[test]
+        fn test_draw_by_agreement() {
+            let mut game: ChessGame = ChessGame::new();
+            game.agree_draw();
+            assert_eq!(
+                game.update_status(),
+                GameStatus::Finished(GameResult::Draw(DrawReason::Agreement))
+            );
+        }
+    }
+
+    mod test_make_move {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::{BoardPosition, CandidateBoardPosition},
+                chess_move::{CastlingSide, Move, MoveError},
+                game_status::{GameResult, GameStatus},
+                ChessGame,
+            },
+            piece::{king::King, knight::Knight, pawn::Pawn, rook::Rook, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_pawn_move_updates_board_pieces_and_turn() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let chess_move: Move = game.make_move(&from, &to).unwrap();
+            assert_eq!(chess_move.from, from);
+            assert_eq!(chess_move.to, to);
+            assert!(matches!(
+                chess_move.piece,
+                ChessPiece::Pawn(Pawn {
+                    has_been_moved: false,
+                    ..
+                })
+            ));
+            assert!(chess_move.captured_piece.is_none());
+            assert!(game.board_data.board[1][4].is_none());
+            assert!(matches!(
+                &game.board_data.board[3][4],
+                Some(ChessPiece::Pawn(Pawn {
+                    has_been_moved: true,
+                    position,
+                    ..
+                })) if *position == to
+            ));
+            assert!(!game.board_data.white_pieces.contains_key(&from));
+            assert!(matches!(
+                game.board_data.white_pieces.get(&to),
+                Some(ChessPiece::Pawn(Pawn {
+                    has_been_moved: true,
+                    ..
+                }))
+            ));
+            assert_eq!(game.turn, 1);
+            assert_eq!(game.get_current_player(), Player::Black);
+        }
+
+        #[test]
+        fn test_capture_removes_the_captured_piece() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][6] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 6,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let chess_move: Move = game.make_move(&from, &to).unwrap();
+            assert!(matches!(
+                chess_move.captured_piece,
+                Some(ChessPiece::Knight(_))
+            ));
+            assert!(!game.board_data.black_pieces.contains_key(&to));
+            assert_eq!(game.board_data.black_pieces.len(), 1);
+            assert!(matches!(
+                game.board_data.board[3][6],
+                Some(ChessPiece::Rook(_))
+            ));
+        }
+
+        #[test]
+        fn test_kingside_castling_moves_the_rook() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 6,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let chess_move: Move = game.make_move(&from, &to).unwrap();
+            assert_eq!(chess_move.flags.castling, Some(CastlingSide::Kingside));
+            assert!(game.board_data.board[0][7].is_none());
+            assert!(matches!(
+                &game.board_data.board[0][5],
+                Some(ChessPiece::Rook(Rook {
+                    has_been_moved: true,
+                    ..
+                }))
+            ));
+            assert!(matches!(
+                game.board_data.white_pieces.get(
+                    &CandidateBoardPosition {
+                        row_index: 0,
+                        column_index: 5,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                ),
+                Some(ChessPiece::Rook(_))
+            ));
+        }
+
+        #[test]
+        fn test_queenside_castling_moves_the_rook() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[7][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 1);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 2,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let chess_move: Move = game.make_move(&from, &to).unwrap();
+            assert_eq!(chess_move.flags.castling, Some(CastlingSide::Queenside));
+            assert!(game.board_data.board[7][0].is_none());
+            assert!(matches!(
+                game.board_data.board[7][3],
+                Some(ChessPiece::Rook(_))
+            ));
+        }
+
+        #[test]
+        fn test_move_giving_check_is_flagged() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let chess_move: Move = game.make_move(&from, &to).unwrap();
+            assert!(chess_move.flags.is_check);
+            assert_eq!(game.status, GameStatus::Ongoing);
+        }
+
+        #[test]
+        fn test_checkmating_move_finishes_the_game() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][7] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][6] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][7] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            assert_eq!(
+                game.status,
+                GameStatus::Finished(GameResult::Checkmate {
+                    winner: Player::White
+                })
+            );
+            assert_eq!(
+                game.make_move(&from, &to).unwrap_err(),
+                MoveError::GameIsFinished
+            );
+        }
+
+        #[test]
+        fn test_moving_from_an_empty_position_is_an_error() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert_eq!(
+                game.make_move(&from, &to).unwrap_err(),
+                MoveError::NoPieceAtPosition(from)
+            );
+        }
+
+        #[test]
+        fn test_moving_an_opposing_piece_is_an_error() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 6,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert_eq!(
+                game.make_move(&from, &to).unwrap_err(),
+                MoveError::NotPlayersPiece {
+                    position: from,
+                    player: Player::White
+                }
+            );
+        }
+
+        #[test]
+        fn test_illegal_move_is_an_error() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 1,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 2,
+                column_index: 1,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert_eq!(
+                game.make_move(&from, &to).unwrap_err(),
+                MoveError::IllegalMove { from, to }
+            );
+            assert_eq!(game.turn, 0);
+        }
+    }
+
+    mod test_undo_and_redo_move {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::{BoardPosition, CandidateBoardPosition},
+                chess_move::Move,
+                game_status::GameStatus,
+                ChessGame,
+            },
+            piece::{king::King, knight::Knight, pawn::Pawn, rook::Rook, ChessPiece},
+            player::Player,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_undo_restores_the_previous_position() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            let undone_move: Move = game.undo_move().unwrap();
+            assert_eq!(undone_move.from, from);
+            assert_eq!(undone_move.to, to);
+            assert_eq!(game.turn, 0);
+            assert!(game.board_data.board[3][4].is_none());
+            assert!(matches!(
+                &game.board_data.board[1][4],
+                Some(ChessPiece::Pawn(Pawn {
+                    has_been_moved: false,
+                    position,
+                    ..
+                })) if *position == from
+            ));
+            assert!(game.board_data.white_pieces.contains_key(&from));
+            assert!(!game.board_data.white_pieces.contains_key(&to));
+            assert!(game.move_history.is_empty());
+        }
+
+        #[test]
+        fn test_undo_restores_a_captured_piece() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][6] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 6,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            game.undo_move();
+            assert!(matches!(
+                game.board_data.black_pieces.get(&to),
+                Some(ChessPiece::Knight(_))
+            ));
+            assert!(matches!(
+                game.board_data.board[3][6],
+                Some(ChessPiece::Knight(_))
+            ));
+            assert!(matches!(
+                game.board_data.white_pieces.get(&from),
+                Some(ChessPiece::Rook(_))
+            ));
+        }
+
+        #[test]
+        fn test_undo_restores_castling() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 6,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            game.undo_move();
+            assert!(game.board_data.board[0][5].is_none());
+            assert!(matches!(
+                game.board_data.board[0][7],
+                Some(ChessPiece::Rook(Rook {
+                    has_been_moved: false,
+                    ..
+                }))
+            ));
+            assert!(game.legal_moves(&Player::White)[&from]
+                .valid_moves
+                .contains(&to));
+        }
+
+        #[test]
+        fn test_undo_restores_the_game_status() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][7] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][6] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][7] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            game.undo_move();
+            assert_eq!(game.status, GameStatus::Ongoing);
+            assert_eq!(game.get_current_player(), Player::White);
+        }
+
+        #[test]
+        fn test_undo_without_history_does_nothing() {
+            let mut game: ChessGame = ChessGame::new();
+            assert!(game.undo_move().is_none());
+            assert_eq!(game.turn, 0);
+        }
+
+        #[test]
+        fn test_redo_replays_the_undone_move() {
+            let mut game: ChessGame = ChessGame::new();
+            let white_from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let white_to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let black_from: BoardPosition = CandidateBoardPosition {
+                row_index: 6,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let black_to: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&white_from, &white_to).unwrap();
+            game.make_move(&black_from, &black_to).unwrap();
+            game.undo_move();
+            game.undo_move();
+            assert_eq!(game.undone_moves.len(), 2);
+            let redone_move: Move = game.redo_move().unwrap();
+            assert_eq!(redone_move.from, white_from);
+            assert_eq!(redone_move.to, white_to);
+            let redone_move: Move = game.redo_move().unwrap();
+            assert_eq!(redone_move.from, black_from);
+            assert_eq!(redone_move.to, black_to);
+            assert!(game.redo_move().is_none());
+            assert_eq!(game.turn, 2);
+            assert_eq!(game.move_history.len(), 2);
+            assert!(matches!(
+                game.board_data.board[4][4],
+                Some(ChessPiece::Pawn(_))
+            ));
+        }
+
+        #[test]
+        fn test_new_move_discards_undone_moves() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let other_to: BoardPosition = CandidateBoardPosition {
+                row_index: 2,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            game.undo_move();
+            game.make_move(&from, &other_to).unwrap();
+            assert!(game.undone_moves.is_empty());
+            assert!(game.redo_move().is_none());
+        }
+
+        #[test]
+        fn test_redo_keeps_the_comment_and_remaining_time() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            game.comment_on_last_move("Best by test".to_string());
+            game.move_history[0].remaining_time = Some(Duration::from_secs(299));
+            game.undo_move();
+            game.redo_move();
+            assert_eq!(
+                game.move_history[0].comment,
+                Some("Best by test".to_string())
+            );
+            assert_eq!(
+                game.move_history[0].remaining_time,
+                Some(Duration::from_secs(299))
+            );
+        }
+
+        #[test]
+        fn test_finishing_the_game_discards_undone_moves() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            game.undo_move();
+            game.resign(&Player::White);
+            assert!(game.undone_moves.is_empty());
+            assert!(game.redo_move().is_none());
+        }
+    }
+
+    mod test_promotion {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::{BoardPosition, CandidateBoardPosition},
+                chess_move::{LegalMove, Move, MoveError, PromotionPiece},
+                ChessGame,
+            },
+            piece::{king::King, knight::Knight, pawn::Pawn, queen::Queen, rook::Rook, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_promoting_push_is_listed_once_per_promotion_piece() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[6][0] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 6,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let pawn_moves: Vec<LegalMove> = game
+                .legal_move_list(&Player::White)
+                .into_iter()
+                .filter(|legal_move| legal_move.from == from)
+                .collect();
+            assert_eq!(
+                pawn_moves,
+                PromotionPiece::ALL
+                    .iter()
+                    .map(|promotion| LegalMove {
+                        from,
+                        to,
+                        promotion: Some(*promotion),
+                    })
+                    .collect::<Vec<LegalMove>>()
+            );
+        }
+
+        #[test]
+        fn test_promoting_capture_is_listed_once_per_promotion_piece() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[1][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][1] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let game: ChessGame = ChessGame::from_board(board, 1);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 1,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let pawn_moves: Vec<LegalMove> = game
+                .legal_move_list(&Player::Black)
+                .into_iter()
+                .filter(|legal_move| legal_move.from == from)
+                .collect();
+            assert_eq!(pawn_moves.len(), 4);
+            assert!(pawn_moves
+                .iter()
+                .all(|legal_move| legal_move.to.get_column_index() == 0
+                    && legal_move.promotion.is_some()));
+        }
+
+        #[test]
+        fn test_non_promoting_moves_have_no_promotion() {
+            let game: ChessGame = ChessGame::new();
+            let legal_move_list: Vec<LegalMove> = game.legal_move_list(&Player::White);
+            assert_eq!(legal_move_list.len(), 20);
+            assert!(legal_move_list
+                .iter()
+                .all(|legal_move| legal_move.promotion.is_none()));
+        }
+
+        #[test]
+        fn test_promotion_replaces_the_pawn_with_a_queen() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[6][0] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 6,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let chess_move: Move = game
+                .make_move_with_promotion(&from, &to, Some(PromotionPiece::Queen))
+                .unwrap();
+            assert_eq!(chess_move.flags.promotion, Some(PromotionPiece::Queen));
+            assert!(matches!(chess_move.piece, ChessPiece::Pawn(_)));
+            assert!(matches!(
+                &game.board_data.board[7][0],
+                Some(ChessPiece::Queen(Queen {
+                    player: Player::White,
+                    position,
+                })) if *position == to
+            ));
+            assert!(matches!(
+                game.board_data.white_pieces.get(&to),
+                Some(ChessPiece::Queen(_))
+            ));
+            assert!(!game.board_data.white_pieces.contains_key(&from));
+            // The new queen gives check along the back row
+            assert!(chess_move.flags.is_check);
+        }
+
+        #[test]
+        fn test_promoting_capture_to_a_knight() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[1][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 1);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 1,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let chess_move: Move = game
+                .make_move_with_promotion(&from, &to, Some(PromotionPiece::Knight))
+                .unwrap();
+            assert!(matches!(
+                chess_move.captured_piece,
+                Some(ChessPiece::Rook(_))
+            ));
+            assert!(matches!(
+                game.board_data.board[0][0],
+                Some(ChessPiece::Knight(Knight {
+                    player: Player::Black,
+                    ..
+                }))
+            ));
+            assert!(matches!(
+                game.board_data.black_pieces.get(&to),
+                Some(ChessPiece::Knight(_))
+            ));
+            assert_eq!(game.board_data.white_pieces.len(), 1);
+        }
+
+        #[test]
+        fn test_promoting_without_a_piece_is_an_error() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[6][0] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 6,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert_eq!(
+                game.make_move(&from, &to).unwrap_err(),
+                MoveError::MissingPromotionPiece { from, to }
+            );
+            assert!(matches!(
+                game.board_data.board[6][0],
+                Some(ChessPiece::Pawn(_))
+            ));
+        }
+
+        #[test]
+        fn test_promotion_piece_for_a_non_promoting_move_is_an_error() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert_eq!(
+                game.make_move_with_promotion(&from, &to, Some(PromotionPiece::Queen))
+                    .unwrap_err(),
+                MoveError::IllegalMove { from, to }
+            );
+        }
+
+        #[test]
+        fn test_undo_and_redo_promotion() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[6][0] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 6,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move_with_promotion(&from, &to, Some(PromotionPiece::Rook))
+                .unwrap();
+            game.undo_move();
+            assert!(game.board_data.board[7][0].is_none());
+            assert!(matches!(
+                game.board_data.white_pieces.get(&from),
+                Some(ChessPiece::Pawn(_))
+            ));
+            game.redo_move();
+            assert!(matches!(
+                game.board_data.white_pieces.get(&to),
+                Some(ChessPiece::Rook(_))
+            ));
+        }
+    }
+
+    mod test_en_passant {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::{BoardPosition, CandidateBoardPosition},
+                chess_move::{LegalMove, Move, MoveError},
+                ChessGame,
+            },
+            piece::{king::King, pawn::Pawn, rook::Rook, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_double_pawn_push_sets_the_en_passant_target() {
+            let mut game: ChessGame = ChessGame::new();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            let skipped_position: BoardPosition = CandidateBoardPosition {
+                row_index: 2,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert_eq!(game.en_passant_target, Some(skipped_position));
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(game.en_passant_target, None);
+        }
+
+        #[test]
+        fn test_white_en_passant_capture_removes_the_passed_pawn() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[4][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 1);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let passed_pawn_position: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert!(game.legal_move_list(&Player::White).contains(&LegalMove {
+                from,
+                to,
+                promotion: None,
+            }));
+            let chess_move: Move = game.make_move(&from, &to).unwrap();
+            assert!(chess_move.flags.en_passant);
+            assert!(matches!(
+                chess_move.captured_piece,
+                Some(ChessPiece::Pawn(Pawn {
+                    player: Player::Black,
+                    ..
+                }))
+            ));
+            assert!(game.board_data.board[4][3].is_none());
+            assert!(!game
+                .board_data
+                .black_pieces
+                .contains_key(&passed_pawn_position));
+            assert_eq!(game.board_data.black_pieces.len(), 1);
+            assert!(matches!(
+                game.board_data.white_pieces.get(&to),
+                Some(ChessPiece::Pawn(_))
+            ));
+        }
+
+        #[test]
+        fn test_black_en_passant_capture_removes_the_passed_pawn() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[3][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 2,
+                column_index: 1,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let chess_move: Move = game
+                .make_move(
+                    &CandidateBoardPosition {
+                        row_index: 3,
+                        column_index: 2,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    &to,
+                )
+                .unwrap();
+            assert!(chess_move.flags.en_passant);
+            assert!(game.board_data.board[3][1].is_none());
+            assert_eq!(game.board_data.white_pieces.len(), 1);
+            assert!(matches!(
+                game.board_data.black_pieces.get(&to),
+                Some(ChessPiece::Pawn(_))
+            ));
+        }
+
+        #[test]
+        fn test_en_passant_is_only_available_immediately() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[4][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 1);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert_eq!(
+                game.make_move(&from, &to).unwrap_err(),
+                MoveError::IllegalMove { from, to }
+            );
+        }
+
+        #[test]
+        fn test_en_passant_capture_cannot_expose_the_king() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[4][0] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[4][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 1);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            // Both pawns leave the row, which would leave the king in check from the rook
+            assert_eq!(
+                game.make_move(&from, &to).unwrap_err(),
+                MoveError::IllegalMove { from, to }
+            );
+        }
+
+        #[test]
+        fn test_undo_en_passant_capture_restores_the_passed_pawn() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[4][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 1);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            let passed_pawn_position: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.undo_move();
+            assert!(matches!(
+                game.board_data.black_pieces.get(&passed_pawn_position),
+                Some(ChessPiece::Pawn(_))
+            ));
+            assert_eq!(
+                game.en_passant_target,
+                Some(
+                    CandidateBoardPosition {
+                        row_index: 5,
+                        column_index: 3,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board)
+                )
+            );
+            game.redo_move();
+            assert!(game.board_data.board[4][3].is_none());
+        }
+    }
+
+    mod test_draw_rules {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+                game_status::{DrawReason, GameResult, GameStatus},
+                ChessGame,
+            },
+            piece::{bishop::Bishop, king::King, knight::Knight, rook::Rook, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_halfmove_clock_resets_on_pawn_moves_and_captures() {
+            let mut game: ChessGame = ChessGame::new();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(game.halfmove_clock, 2);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(game.halfmove_clock, 0);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(game.halfmove_clock, 0);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(game.halfmove_clock, 1);
+        }
+
+        #[test]
+        fn test_fifty_move_rule_can_be_claimed() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            game.halfmove_clock = 99;
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(
+                game.status,
+                GameStatus::DrawClaimable(DrawReason::FiftyMoveRule)
+            );
+            game.claim_draw();
+            assert_eq!(
+                game.status,
+                GameStatus::Finished(GameResult::Draw(DrawReason::FiftyMoveRule))
+            );
+        }
+
+        #[test]
+        fn test_unclaimed_draw_lets_the_game_continue() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            game.halfmove_clock = 99;
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(
+                game.status,
+                GameStatus::DrawClaimable(DrawReason::FiftyMoveRule)
+            );
+            // A capture resets the count
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(game.status, GameStatus::Ongoing);
+        }
+
+        #[test]
+        fn test_seventy_five_move_rule_is_automatic() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            game.halfmove_clock = 149;
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(
+                game.status,
+                GameStatus::Finished(GameResult::Draw(DrawReason::SeventyFiveMoveRule))
+            );
+        }
+
+        #[test]
+        fn test_claim_draw_without_a_claimable_draw_does_nothing() {
+            let mut game: ChessGame = ChessGame::new();
+            game.claim_draw();
+            assert_eq!(game.status, GameStatus::Ongoing);
+        }
+
+        #[test]
+        fn test_threefold_repetition_can_be_claimed() {
+            let mut game: ChessGame = ChessGame::new();
+            for (from, to) in [
+                ((0, 6), (2, 5)),
+                ((7, 6), (5, 5)),
+                ((2, 5), (0, 6)),
+                ((5, 5), (7, 6)),
+            ]
+            .iter()
+            .cycle()
+            .take(7)
+            {
+                game.make_move(
+                    &CandidateBoardPosition {
+                        row_index: from.0,
+                        column_index: from.1,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    &CandidateBoardPosition {
+                        row_index: to.0,
+                        column_index: to.1,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),
+                )
+                .unwrap();
+            }
+            assert_eq!(game.status, GameStatus::Ongoing);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            // The starting position has now been reached three times
+            assert_eq!(
+                game.status,
+                GameStatus::DrawClaimable(DrawReason::ThreefoldRepetition)
+            );
+        }
+
+        #[test]
+        fn test_fivefold_repetition_is_automatic() {
+            let mut game: ChessGame = ChessGame::new();
+            for (from, to) in [
+                ((0, 6), (2, 5)),
+                ((7, 6), (5, 5)),
+                ((2, 5), (0, 6)),
+                ((5, 5), (7, 6)),
+            ]
+            .iter()
+            .cycle()
+            .take(16)
+            {
+                game.make_move(
+                    &CandidateBoardPosition {
+                        row_index: from.0,
+                        column_index: from.1,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    &CandidateBoardPosition {
+                        row_index: to.0,
+                        column_index: to.1,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),
+                )
+                .unwrap();
+            }
+            assert_eq!(
+                game.status,
+                GameStatus::Finished(GameResult::Draw(DrawReason::FivefoldRepetition))
+            );
+        }
+
+        #[test]
+        fn test_uncapturable_en_passant_target_does_not_prevent_repetition() {
+            let mut game: ChessGame = ChessGame::new();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            for (from, to) in [
+                ((7, 6), (5, 5)),
+                ((0, 6), (2, 5)),
+                ((5, 5), (7, 6)),
+                ((2, 5), (0, 6)),
+            ]
+            .iter()
+            .cycle()
+            .take(8)
+            {
+                game.make_move(
+                    &CandidateBoardPosition {
+                        row_index: from.0,
+                        column_index: from.1,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),
+                    &CandidateBoardPosition {
+                        row_index: to.0,
+                        column_index: to.1,
+                    }
+                    .validate_candidate_position_and_unwrap(&game.board_data.board),
+                )
+                .unwrap();
+            }
+            assert_eq!(
+                game.status,
+                GameStatus::DrawClaimable(DrawReason::ThreefoldRepetition)
+            );
+        }
+
+        #[test]
+        fn test_capturing_the_last_piece_is_a_draw_by_insufficient_material() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][3] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][4] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            assert_eq!(
+                game.status,
+                GameStatus::Finished(GameResult::Draw(DrawReason::InsufficientMaterial))
+            );
+        }
+
+        #[test]
+        fn test_undo_restores_the_halfmove_clock_and_position_history() {
+            let mut game: ChessGame = ChessGame::new();
+            game.make_move(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+                &CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board),
+            )
+            .unwrap();
+            game.undo_move();
+            assert_eq!(game.halfmove_clock, 0);
+            assert_eq!(game.position_history.len(), 1);
+        }
+    }
+
+    mod test_clock {
+        use crate::{
+            game::{
+                board_position::get_position,
+                chess_move::MoveError,
+                game_status::{DrawReason, GameResult, GameStatus},
+                ChessGame,
+            },
+            player::Player,
+        };
+        use std::time::{Duration, Instant};
+
+        // Starts a one minute clock for the player to move that has already run out
+        fn start_expired_clock(game: &mut ChessGame) {
+            game.start_clock("1".parse().unwrap());
+            let started_at: Instant = Instant::now().checked_sub(Duration::from_secs(61)).unwrap();
+            let player: Player = game.get_current_player();
+            game.clock.as_mut().unwrap().start(player, started_at);
+        }
+
+        #[test]
+        fn test_running_out_of_time_loses() {
+            let mut game: ChessGame = ChessGame::new();
+            start_expired_clock(&mut game);
+            assert_eq!(
+                game.check_flag_fall(),
+                GameStatus::Finished(GameResult::Timeout {
+                    winner: Player::Black
+                })
+            );
+            assert_eq!(game.clock.as_ref().unwrap().get_running_player(), None);
+        }
+
+        #[test]
+        fn test_running_out_of_time_against_insufficient_material_draws() {
+            // Black has only their king left, so can never checkmate White
+            let mut game: ChessGame =
+                ChessGame::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+            start_expired_clock(&mut game);
+            assert_eq!(
+                game.check_flag_fall(),
+                GameStatus::Finished(GameResult::Draw(DrawReason::TimeoutVsInsufficientMaterial))
+            );
+        }
+
+        #[test]
+        fn test_move_made_after_running_out_of_time_is_refused() {
+            let mut game: ChessGame = ChessGame::new();
+            start_expired_clock(&mut game);
+            let (from, to) = (get_position("e2"), get_position("e4"));
+            assert_eq!(
+                game.make_move(&from, &to).err(),
+                Some(MoveError::GameIsFinished)
+            );
+            assert!(game.move_history.is_empty());
+            assert_eq!(
+                game.status,
+                GameStatus::Finished(GameResult::Timeout {
+                    winner: Player::Black
+                })
+            );
+        }
+
+        #[test]
+        fn test_moves_record_the_remaining_time() {
+            let mut game: ChessGame = ChessGame::new();
+            game.start_clock("5+3".parse().unwrap());
+            let (from, to) = (get_position("e2"), get_position("e4"));
+            game.make_move(&from, &to).unwrap();
+
+            // White gets the increment, and it is now Black's clock that is running
+            let remaining_time: Duration = game.move_history[0].remaining_time.unwrap();
+            assert!(remaining_time > Duration::from_secs(302));
+            assert!(remaining_time <= Duration::from_secs(303));
+            assert_eq!(
+                game.clock.as_ref().unwrap().get_running_player(),
+                Some(Player::Black)
+            );
+        }
+
+        #[test]
+        fn test_moves_can_not_be_undone_against_the_clock() {
+            let mut game: ChessGame = ChessGame::new();
+            game.start_clock("5".parse().unwrap());
+            let (from, to) = (get_position("e2"), get_position("e4"));
+            game.make_move(&from, &to).unwrap();
+            assert!(game.undo_move().is_none());
+            assert_eq!(game.move_history.len(), 1);
+            assert_eq!(
+                game.clock.as_ref().unwrap().get_running_player(),
+                Some(game.get_current_player())
+            );
+        }
+
+        #[test]
+        fn test_finishing_the_game_stops_the_clock() {
+            let mut game: ChessGame = ChessGame::new();
+            game.start_clock("5".parse().unwrap());
+            game.resign(&Player::White);
+            assert_eq!(game.clock.as_ref().unwrap().get_running_player(), None);
+            assert_eq!(game.check_flag_fall(), game.status);
+        }
+
+        #[test]
+        fn test_untimed_game_never_runs_out_of_time() {
+            let mut game: ChessGame = ChessGame::new();
+            assert_eq!(game.check_flag_fall(), GameStatus::Ongoing);
+            let (from, to) = (get_position("e2"), get_position("e4"));
+            game.make_move(&from, &to).unwrap();
+            assert_eq!(game.move_history[0].remaining_time, None);
+        }
     }
 }
@@ -1,14 +1,217 @@
-use crate::piece::ChessPiece;
+use crate::{
+    piece::{
+        knight::KNIGHT_JUMPS, ChessPiece, ChessPieceTrait, DIAGONAL_DIRECTIONS,
+        ORTHOGONAL_DIRECTIONS,
+    },
+    player::Player,
+};
 
-use super::BoardPosition;
+use super::{
+    board_position::{BoardPosition, CandidateBoardPosition},
+    chess_move::CastlingSide,
+};
 use std::collections::HashMap;
 
+#[derive(Clone)]
 pub struct ChessBoardData {
     pub board: ChessBoard,
     // black_pieces and white_pieces need to be updated when any pieces are updated on the board
     pub black_pieces: HashMap<BoardPosition, ChessPiece>,
     pub white_pieces: HashMap<BoardPosition, ChessPiece>,
 }
+
+impl ChessBoardData {
+    // Builds the black_pieces and white_pieces maps from the pieces placed on `board`
+    pub fn new(board: ChessBoard) -> Self {
+        let mut black_pieces: HashMap<BoardPosition, ChessPiece> = HashMap::new();
+        let mut white_pieces: HashMap<BoardPosition, ChessPiece> = HashMap::new();
+
+        for piece in board.iter().flatten().flatten() {
+            match piece.get_player() {
+                Player::Black => black_pieces.insert(*piece.get_board_position(), piece.clone()),
+                Player::White => white_pieces.insert(*piece.get_board_position(), piece.clone()),
+            };
+        }
+
+        Self {
+            board,
+            black_pieces,
+            white_pieces,
+        }
+    }
+
+    pub fn get_player_pieces(&self, player: &Player) -> &HashMap<BoardPosition, ChessPiece> {
+        match player {
+            Player::Black => &self.black_pieces,
+            Player::White => &self.white_pieces,
+        }
+    }
+
+    pub fn get_player_pieces_mut(
+        &mut self,
+        player: &Player,
+    ) -> &mut HashMap<BoardPosition, ChessPiece> {
+        match player {
+            Player::Black => &mut self.black_pieces,
+            Player::White => &mut self.white_pieces,
+        }
+    }
+
+    /*
+        Moves the piece on `from` to `to`, keeping the board, the black_pieces and white_pieces
+        maps, the piece's own position and its `has_been_moved` flag in sync. Any opposing
+        piece standing on `to` is removed from the board and returned.
+
+        No move validation is performed here; callers are expected to only pass moves
+        produced by the piece's `valid_moves_and_captures`.
+    */
+    pub fn move_piece(&mut self, from: &BoardPosition, to: &BoardPosition) -> Option<ChessPiece> {
+        let mut piece: ChessPiece = self.board[from.get_row_index()][from.get_column_index()]
+            .take()
+            .unwrap_or_else(|| panic!("No piece to move at {:?}", from));
+        let player: Player = *piece.get_player();
+        piece.set_board_position(*to);
+        piece.mark_as_moved();
+
+        let captured_piece: Option<ChessPiece> =
+            self.board[to.get_row_index()][to.get_column_index()].take();
+        if captured_piece.is_some() {
+            self.get_player_pieces_mut(&player.get_opponent())
+                .remove(to);
+        }
+
+        let player_pieces = self.get_player_pieces_mut(&player);
+        player_pieces.remove(from);
+        player_pieces.insert(*to, piece.clone());
+        self.board[to.get_row_index()][to.get_column_index()] = Some(piece);
+
+        captured_piece
+    }
+
+    // Puts `piece` on the board at its own position, replacing whatever was there
+    pub fn place_piece(&mut self, piece: ChessPiece) {
+        let position: BoardPosition = *piece.get_board_position();
+        self.remove_piece(&position);
+        self.get_player_pieces_mut(piece.get_player())
+            .insert(position, piece.clone());
+        self.board[position.get_row_index()][position.get_column_index()] = Some(piece);
+    }
+
+    // Takes the piece at `position` off the board, if there is one
+    pub fn remove_piece(&mut self, position: &BoardPosition) -> Option<ChessPiece> {
+        let removed_piece: ChessPiece =
+            self.board[position.get_row_index()][position.get_column_index()].take()?;
+        self.get_player_pieces_mut(removed_piece.get_player())
+            .remove(position);
+        Some(removed_piece)
+    }
+
+    pub fn get_king_position(&self, player: &Player) -> Option<&BoardPosition> {
+        self.get_player_pieces(player)
+            .values()
+            .find(|piece| matches!(piece, ChessPiece::King(_)))
+            .map(|king| king.get_board_position())
+    }
+
+    pub fn is_square_attacked(&self, position: &BoardPosition, by: &Player) -> bool {
+        is_square_attacked(&self.board, position, by)
+    }
+
+    pub fn is_in_check(&self, player: &Player) -> bool {
+        self.get_king_position(player)
+            .is_some_and(|position| self.is_square_attacked(position, &player.get_opponent()))
+    }
+
+    /*
+        A player keeps the right to castle on a side for as long as neither their king nor the
+        rook on that side has moved. Whether castling is possible right now (nothing in between,
+        no attacked positions) is up to `King::valid_castling_moves`.
+    */
+    pub fn has_castling_right(&self, player: &Player, castling_side: CastlingSide) -> bool {
+        let home_row: &ChessRow = match player {
+            Player::White => &self.board[0],
+            Player::Black => &self.board[7],
+        };
+        let rook_column_index: usize = match castling_side {
+            CastlingSide::Kingside => 7,
+            CastlingSide::Queenside => 0,
+        };
+        matches!(&home_row[4], Some(ChessPiece::King(king)) if king.player == *player && !king.has_been_moved)
+            && matches!(&home_row[rook_column_index], Some(ChessPiece::Rook(rook)) if rook.player == *player && !rook.has_been_moved)
+    }
+
+    /*
+        Returns true if neither player can possibly checkmate the other: a king against a king, a
+        king and a single bishop or knight against a king, or kings and bishops only where every
+        bishop stands on the same coloured position.
+    */
+    pub fn has_insufficient_material(&self) -> bool {
+        let mut knight_count: usize = 0;
+        let mut bishop_position_colours: Vec<usize> = vec![];
+        for piece in self.black_pieces.values().chain(self.white_pieces.values()) {
+            match piece {
+                ChessPiece::King(_) => {}
+                ChessPiece::Knight(_) => knight_count += 1,
+                ChessPiece::Bishop(bishop) => bishop_position_colours.push(
+                    (bishop.position.get_row_index() + bishop.position.get_column_index()) % 2,
+                ),
+                ChessPiece::Pawn(_) | ChessPiece::Rook(_) | ChessPiece::Queen(_) => return false,
+            }
+        }
+
+        match knight_count {
+            0 => bishop_position_colours
+                .windows(2)
+                .all(|colours| colours[0] == colours[1]),
+            1 => bishop_position_colours.is_empty(),
+            _ => false,
+        }
+    }
+
+    /*
+        Returns true if `player` could checkmate their opponent by some series of legal moves,
+        however unlikely, which decides whether running out of time loses or draws.
+
+        A lone king can never checkmate. A single knight, or bishops that all stand on the same
+        coloured position, can only do so when an opposing piece blocks the king in. For bishops,
+        that piece has to be able to stand on the positions of the other colour.
+    */
+    pub fn has_mating_material(&self, player: &Player) -> bool {
+        let mut knight_count: usize = 0;
+        let mut bishop_position_colours: Vec<usize> = vec![];
+        for piece in self.get_player_pieces(player).values() {
+            match piece {
+                ChessPiece::King(_) => {}
+                ChessPiece::Knight(_) => knight_count += 1,
+                ChessPiece::Bishop(bishop) => bishop_position_colours.push(
+                    (bishop.position.get_row_index() + bishop.position.get_column_index()) % 2,
+                ),
+                ChessPiece::Pawn(_) | ChessPiece::Rook(_) | ChessPiece::Queen(_) => return true,
+            }
+        }
+
+        let opposing_pieces = self.get_player_pieces(&player.get_opponent());
+        let has_opposing_blocker: bool = opposing_pieces
+            .values()
+            .any(|piece| !matches!(piece, ChessPiece::King(_)));
+        match (knight_count, bishop_position_colours.as_slice()) {
+            (0, []) => false,
+            (1, []) => has_opposing_blocker,
+            (0, [colour, ..]) if bishop_position_colours.iter().all(|other| other == colour) => {
+                opposing_pieces.values().any(|piece| match piece {
+                    ChessPiece::King(_) => false,
+                    ChessPiece::Bishop(bishop) => {
+                        (bishop.position.get_row_index() + bishop.position.get_column_index()) % 2
+                            != *colour
+                    }
+                    _ => true,
+                })
+            }
+            _ => true,
+        }
+    }
+}
+
 pub type ChessBoard = [ChessRow; 8];
 pub type ChessRow = [Option<ChessPiece>; 8];
 
@@ -16,3 +219,1335 @@ pub fn initialize_empty_board() -> ChessBoard {
     let board: ChessBoard = std::array::from_fn(|_| std::array::from_fn(|_| None));
     board
 }
+
+/*
+    Returns true if any piece belonging to `by` could capture a piece standing on `position`.
+
+    Rather than generating every move for every opposing piece, we work backwards from
+    `position`: we look outwards along each knight jump, pawn diagonal, king step and sliding
+    ray, and check whether the piece found there is one that attacks in that manner. This also
+    means a king never needs to generate its own moves (including castling) to answer the
+    question, which would otherwise recurse forever.
+*/
+pub fn is_square_attacked(board: &ChessBoard, position: &BoardPosition, by: &Player) -> bool {
+    let piece_at_offset = |row_offset: i32, column_offset: i32| -> Option<&ChessPiece> {
+        CandidateBoardPosition {
+            row_index: position.get_row_index() as i32 + row_offset,
+            column_index: position.get_column_index() as i32 + column_offset,
+        }
+        .validate_candidate_position(board)
+        .and_then(|position| board[position.get_row_index()][position.get_column_index()].as_ref())
+        .filter(|piece| piece.get_player() == by)
+    };
+
+    // Knights attack every position that they can jump to
+    let is_attacked_by_knight = KNIGHT_JUMPS.iter().any(|(row_offset, column_offset)| {
+        piece_at_offset(*row_offset, *column_offset)
+            .is_some_and(|piece| matches!(piece, ChessPiece::Knight(_)))
+    });
+
+    // Pawns only attack diagonally forward, so an attacking pawn sits one row "behind" position
+    let pawn_direction: i32 = match by {
+        Player::White => 1,
+        Player::Black => -1,
+    };
+    let is_attacked_by_pawn = [-1, 1].iter().any(|column_offset| {
+        piece_at_offset(-pawn_direction, *column_offset)
+            .is_some_and(|piece| matches!(piece, ChessPiece::Pawn(_)))
+    });
+
+    let is_attacked_by_king = ORTHOGONAL_DIRECTIONS
+        .iter()
+        .chain(DIAGONAL_DIRECTIONS.iter())
+        .any(|(row_offset, column_offset)| {
+            piece_at_offset(*row_offset, *column_offset)
+                .is_some_and(|piece| matches!(piece, ChessPiece::King(_)))
+        });
+
+    // The first piece found along a ray is the only one that can attack through it
+    let first_piece_in_direction = |row_offset: i32, column_offset: i32| -> Option<&ChessPiece> {
+        let mut distance: i32 = 1;
+        loop {
+            let candidate_position = CandidateBoardPosition {
+                row_index: position.get_row_index() as i32 + row_offset * distance,
+                column_index: position.get_column_index() as i32 + column_offset * distance,
+            }
+            .validate_candidate_position(board)?;
+            if let Some(piece) =
+                &board[candidate_position.get_row_index()][candidate_position.get_column_index()]
+            {
+                return Some(piece);
+            }
+            distance += 1;
+        }
+    };
+
+    let is_attacked_orthogonally =
+        ORTHOGONAL_DIRECTIONS
+            .iter()
+            .any(|(row_offset, column_offset)| {
+                first_piece_in_direction(*row_offset, *column_offset).is_some_and(|piece| {
+                    piece.get_player() == by
+                        && matches!(piece, ChessPiece::Rook(_) | ChessPiece::Queen(_))
+                })
+            });
+
+    let is_attacked_diagonally = DIAGONAL_DIRECTIONS
+        .iter()
+        .any(|(row_offset, column_offset)| {
+            first_piece_in_direction(*row_offset, *column_offset).is_some_and(|piece| {
+                piece.get_player() == by
+                    && matches!(piece, ChessPiece::Bishop(_) | ChessPiece::Queen(_))
+            })
+        });
+
+    is_attacked_by_knight
+        || is_attacked_by_pawn
+        || is_attacked_by_king
+        || is_attacked_orthogonally
+        || is_attacked_diagonally
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_new {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard, ChessBoardData},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{king::King, pawn::Pawn, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_pieces_are_split_by_player() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert_eq!(board_data.white_pieces.len(), 2);
+            assert_eq!(board_data.black_pieces.len(), 1);
+            assert!(matches!(
+                board_data.black_pieces.get(
+                    &CandidateBoardPosition {
+                        row_index: 7,
+                        column_index: 4,
+                    }
+                    .validate_candidate_position_and_unwrap(&board_data.board)
+                ),
+                Some(ChessPiece::King(_))
+            ));
+        }
+
+        #[test]
+        fn test_get_king_position() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][3] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert_eq!(
+                board_data.get_king_position(&Player::Black),
+                Some(
+                    &CandidateBoardPosition {
+                        row_index: 7,
+                        column_index: 3,
+                    }
+                    .validate_candidate_position_and_unwrap(&board_data.board)
+                )
+            );
+        }
+    }
+
+    mod test_move_piece {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard, ChessBoardData},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{knight::Knight, rook::Rook, ChessPiece, ChessPieceTrait},
+            player::Player,
+        };
+
+        #[test]
+        fn test_move_keeps_board_and_pieces_in_sync() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let mut board_data: ChessBoardData = ChessBoardData::new(board);
+            let from = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board_data.board);
+            let to = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board_data.board);
+            let captured_piece = board_data.move_piece(&from, &to);
+            assert!(captured_piece.is_none());
+            assert!(board_data.board[0][0].is_none());
+            assert_eq!(
+                board_data.board[5][0]
+                    .as_ref()
+                    .map(|piece| piece.get_board_position()),
+                Some(&to)
+            );
+            assert!(!board_data.white_pieces.contains_key(&from));
+            assert_eq!(
+                board_data
+                    .white_pieces
+                    .get(&to)
+                    .map(|piece| piece.get_board_position()),
+                Some(&to)
+            );
+        }
+
+        #[test]
+        fn test_capture_removes_opposing_piece() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[5][0] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let mut board_data: ChessBoardData = ChessBoardData::new(board);
+            let from = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board_data.board);
+            let to = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board_data.board);
+            let captured_piece = board_data.move_piece(&from, &to);
+            assert!(matches!(captured_piece, Some(ChessPiece::Knight(_))));
+            assert!(board_data.black_pieces.is_empty());
+            assert!(matches!(
+                board_data.white_pieces.get(&to),
+                Some(ChessPiece::Rook(_))
+            ));
+        }
+    }
+
+    mod test_is_in_check {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard, ChessBoardData},
+                board_position::CandidateBoardPosition,
+                ChessGame,
+            },
+            piece::{king::King, knight::Knight, pawn::Pawn, rook::Rook, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_starting_position_is_not_check() {
+            let game: ChessGame = ChessGame::new();
+            assert!(!game.board_data.is_in_check(&Player::White));
+            assert!(!game.board_data.is_in_check(&Player::Black));
+        }
+
+        #[test]
+        fn test_white_king_attacked_by_rook_is_in_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(board_data.is_in_check(&Player::White));
+        }
+
+        #[test]
+        fn test_black_king_attacked_by_knight_is_in_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[5][3] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(board_data.is_in_check(&Player::Black));
+        }
+
+        #[test]
+        fn test_blocked_attack_is_not_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.is_in_check(&Player::White));
+        }
+
+        #[test]
+        fn test_attacking_player_is_not_in_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][0] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.is_in_check(&Player::Black));
+        }
+
+        #[test]
+        fn test_player_without_a_king_is_not_in_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.is_in_check(&Player::White));
+        }
+    }
+
+    mod test_is_square_attacked {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, is_square_attacked, ChessBoard},
+                board_position::{BoardPosition, CandidateBoardPosition},
+            },
+            piece::{
+                bishop::Bishop, king::King, knight::Knight, pawn::Pawn, queen::Queen, rook::Rook,
+                ChessPiece,
+            },
+            player::Player,
+        };
+
+        #[test]
+        fn test_empty_board_is_not_attacked() {
+            let board: ChessBoard = initialize_empty_board();
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_knight_attack() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[2][1] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(is_square_attacked(&board, &position, &Player::Black));
+        }
+
+        #[test]
+        fn test_knight_does_not_attack_for_the_other_player() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[2][1] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_white_pawn_attacks_diagonally_upward() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[1][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 2,
+                column_index: 2,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_white_pawn_does_not_attack_forward() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[1][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 2,
+                column_index: 1,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_white_pawn_does_not_attack_diagonally_downward() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[1][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 2,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_black_pawn_attacks_diagonally_downward() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[6][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(is_square_attacked(&board, &position, &Player::Black));
+        }
+
+        #[test]
+        fn test_black_pawn_does_not_attack_diagonally_upward() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[6][1] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::Black));
+        }
+
+        #[test]
+        fn test_king_attacks_adjacent_position() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[3][3] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_king_does_not_attack_distant_position() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[3][3] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 5,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_rook_attacks_along_a_row() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 7,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_rook_attack_is_blocked() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 7,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_rook_does_not_attack_diagonally() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 7,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_bishop_attacks_along_a_diagonal() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 7,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(is_square_attacked(&board, &position, &Player::Black));
+        }
+
+        #[test]
+        fn test_bishop_attack_is_blocked() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[4][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 7,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::Black));
+        }
+
+        #[test]
+        fn test_bishop_does_not_attack_along_a_column() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 0,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(!is_square_attacked(&board, &position, &Player::Black));
+        }
+
+        #[test]
+        fn test_queen_attacks_along_a_column() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][3] = Some(ChessPiece::Queen(Queen {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 7,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(is_square_attacked(&board, &position, &Player::White));
+        }
+
+        #[test]
+        fn test_queen_attacks_along_a_diagonal() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][3] = Some(ChessPiece::Queen(Queen {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 6,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert!(is_square_attacked(&board, &position, &Player::White));
+        }
+    }
+
+    mod test_has_insufficient_material {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard, ChessBoardData},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{
+                bishop::Bishop, king::King, knight::Knight, pawn::Pawn, rook::Rook, ChessPiece,
+            },
+            player::Player,
+        };
+
+        #[test]
+        fn test_kings_only() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(board_data.has_insufficient_material());
+        }
+
+        #[test]
+        fn test_king_and_bishop_against_king() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][3] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(board_data.has_insufficient_material());
+        }
+
+        #[test]
+        fn test_king_and_knight_against_king() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[5][5] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(board_data.has_insufficient_material());
+        }
+
+        #[test]
+        fn test_bishops_on_the_same_coloured_positions() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][2] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[7][5] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[2][2] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(board_data.has_insufficient_material());
+        }
+
+        #[test]
+        fn test_bishops_on_opposite_coloured_positions() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][2] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[7][2] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.has_insufficient_material());
+        }
+
+        #[test]
+        fn test_king_and_two_knights_against_king() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][1] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[0][6] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.has_insufficient_material());
+        }
+
+        #[test]
+        fn test_knight_against_bishop() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][1] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[7][2] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.has_insufficient_material());
+        }
+
+        #[test]
+        fn test_single_pawn_is_sufficient() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][0] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.has_insufficient_material());
+        }
+
+        #[test]
+        fn test_single_rook_is_sufficient() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.has_insufficient_material());
+        }
+    }
+
+    mod test_has_castling_right {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard, ChessBoardData},
+                board_position::CandidateBoardPosition,
+                chess_move::CastlingSide,
+                ChessGame,
+            },
+            piece::{king::King, rook::Rook, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_starting_position_has_all_castling_rights() {
+            let board_data: ChessBoardData = ChessGame::new().board_data;
+            for player in [Player::White, Player::Black] {
+                assert!(board_data.has_castling_right(&player, CastlingSide::Kingside));
+                assert!(board_data.has_castling_right(&player, CastlingSide::Queenside));
+            }
+        }
+
+        #[test]
+        fn test_moved_rook_loses_its_castling_right() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.has_castling_right(&Player::White, CastlingSide::Queenside));
+            assert!(board_data.has_castling_right(&Player::White, CastlingSide::Kingside));
+        }
+
+        #[test]
+        fn test_moved_king_loses_both_castling_rights() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][4] = Some(ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[7][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(!board_data.has_castling_right(&Player::Black, CastlingSide::Kingside));
+            assert!(!board_data.has_castling_right(&Player::Black, CastlingSide::Queenside));
+        }
+
+        #[test]
+        fn test_missing_rook_has_no_castling_right() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][4] = Some(ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let board_data: ChessBoardData = ChessBoardData::new(board);
+            assert!(board_data.has_castling_right(&Player::White, CastlingSide::Kingside));
+            assert!(!board_data.has_castling_right(&Player::White, CastlingSide::Queenside));
+        }
+    }
+
+    mod test_has_mating_material {
+        use crate::{
+            game::{board::ChessBoardData, ChessGame},
+            player::Player,
+        };
+
+        fn board_data(fen: &str) -> ChessBoardData {
+            ChessGame::from_fen(fen).unwrap().board_data
+        }
+
+        #[test]
+        fn test_lone_king() {
+            let board_data: ChessBoardData = board_data("4k3/8/8/8/8/8/3QP3/4K3 w - - 0 1");
+            assert!(!board_data.has_mating_material(&Player::Black));
+            assert!(board_data.has_mating_material(&Player::White));
+        }
+
+        #[test]
+        fn test_knight_needs_an_opposing_piece_to_block_the_king() {
+            assert!(
+                !board_data("4k3/8/8/8/8/8/8/4KN2 w - - 0 1").has_mating_material(&Player::White)
+            );
+            assert!(
+                board_data("4k3/4p3/8/8/8/8/8/4KN2 w - - 0 1").has_mating_material(&Player::White)
+            );
+        }
+
+        #[test]
+        fn test_bishops_on_one_colour() {
+            // Both of White's bishops stand on light positions
+            assert!(
+                !board_data("4k3/8/8/8/8/8/4B3/4KB2 w - - 0 1").has_mating_material(&Player::White)
+            );
+            // A black bishop on the same light positions can never block its king in
+            assert!(
+                !board_data("4k1b1/8/8/8/8/8/8/4KB2 w - - 0 1").has_mating_material(&Player::White)
+            );
+            assert!(
+                board_data("4kb2/8/8/8/8/8/8/4KB2 w - - 0 1").has_mating_material(&Player::White)
+            );
+        }
+
+        #[test]
+        fn test_bishop_and_knight() {
+            assert!(
+                board_data("4k3/8/8/8/8/8/8/4KBN1 w - - 0 1").has_mating_material(&Player::White)
+            );
+        }
+    }
+}
@@ -3,8 +3,10 @@ use crate::{
     player::Player,
 };
 
+#[cfg(test)]
+use super::board::initialize_empty_board;
 use super::board::ChessBoard;
-use std::hash::Hash;
+use std::{fmt, hash::Hash};
 
 #[derive(Debug)]
 pub struct CandidateBoardPosition {
@@ -13,6 +15,19 @@ pub struct CandidateBoardPosition {
 }
 
 impl CandidateBoardPosition {
+    // Reads a position written in algebraic notation, e.g. "e4". It still needs to be validated.
+    pub fn from_algebraic_notation(notation: &str) -> Option<CandidateBoardPosition> {
+        let mut chars = notation.chars();
+        let (column_letter, row_digit) = (chars.next()?, chars.next()?);
+        if chars.next().is_some() || !column_letter.is_ascii_lowercase() {
+            return None;
+        }
+        Some(CandidateBoardPosition {
+            row_index: row_digit.to_digit(10)? as i32 - 1,
+            column_index: column_letter as i32 - 'a' as i32,
+        })
+    }
+
     fn is_position_within_bounds(&self, board: &ChessBoard) -> bool {
         let board_size = board.len() as i32; // Assuming a square board
         self.row_index >= 0
@@ -36,10 +51,18 @@ impl CandidateBoardPosition {
     }
 
     pub fn validate_candidate_position_and_unwrap(&self, board: &ChessBoard) -> BoardPosition {
-        self.validate_candidate_position(&board).unwrap()
+        self.validate_candidate_position(board).unwrap()
     }
 }
 
+// Reads a position in algebraic notation that a test knows to be on the board, e.g. "e4"
+#[cfg(test)]
+pub fn get_position(notation: &str) -> BoardPosition {
+    CandidateBoardPosition::from_algebraic_notation(notation)
+        .unwrap()
+        .validate_candidate_position_and_unwrap(&initialize_empty_board())
+}
+
 /*
     BoardPosition fields are private. To obtain an instance of BoardPosition, create a CandidateBoardPosition
     instance and invoke `validate_capture_position` to receive an instance of Option<BoardPosition>
@@ -48,7 +71,7 @@ impl CandidateBoardPosition {
     is valid and you want to directly receive an instance of BoardPosition. If the position is invalid,
     a panic will occur.
 */
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub struct BoardPosition {
     row_index: usize,
     column_index: usize,
@@ -57,16 +80,14 @@ pub struct BoardPosition {
 impl BoardPosition {
     pub fn is_move_valid(&self, board: &ChessBoard) -> bool {
         let space_is_unoccupied: bool = {
-            let space: Option<&ChessPiece> =
-                board[self.row_index as usize][self.column_index as usize].as_ref();
+            let space: Option<&ChessPiece> = board[self.row_index][self.column_index].as_ref();
             space.is_none()
         };
         space_is_unoccupied
     }
 
     pub fn is_capture_valid(&self, player: &Player, board: &ChessBoard) -> bool {
-        let captured_piece: Option<&ChessPiece> =
-            board[self.row_index as usize][self.column_index as usize].as_ref();
+        let captured_piece: Option<&ChessPiece> = board[self.row_index][self.column_index].as_ref();
         // Returns true if captured_piece is Some and piece.player() != player, otherwise returns false
         captured_piece.is_some_and(|piece: &ChessPiece| piece.get_player() != player)
     }
@@ -94,9 +115,56 @@ impl BoardPosition {
     }
 }
 
+// Displays the position in algebraic notation, e.g. "e4"
+impl fmt::Display for BoardPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.get_column_letter(), self.row_index + 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod candidate_board_position {
+        mod test_from_algebraic_notation {
+            use crate::game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::{BoardPosition, CandidateBoardPosition},
+            };
+
+            #[test]
+            fn test_valid_notation() {
+                let board: ChessBoard = initialize_empty_board();
+                let position: Option<BoardPosition> =
+                    CandidateBoardPosition::from_algebraic_notation("e4")
+                        .and_then(|position| position.validate_candidate_position(&board));
+                assert_eq!(
+                    position,
+                    Some(BoardPosition {
+                        row_index: 3,
+                        column_index: 4
+                    })
+                );
+            }
+
+            #[test]
+            fn test_notation_off_the_board_is_invalid_once_validated() {
+                let board: ChessBoard = initialize_empty_board();
+                for notation in ["i1", "a9", "a0"] {
+                    let position: Option<BoardPosition> =
+                        CandidateBoardPosition::from_algebraic_notation(notation)
+                            .and_then(|position| position.validate_candidate_position(&board));
+                    assert_eq!(position, None);
+                }
+            }
+
+            #[test]
+            fn test_malformed_notation() {
+                for notation in ["", "e", "4e", "E4", "e44"] {
+                    assert!(CandidateBoardPosition::from_algebraic_notation(notation).is_none());
+                }
+            }
+        }
+
         mod test_is_position_within_bounds {
             use crate::game::{
                 board::{initialize_empty_board, ChessBoard},
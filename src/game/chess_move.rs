@@ -0,0 +1,174 @@
+use crate::{
+    game::board_position::BoardPosition,
+    piece::{
+        bishop::Bishop, knight::Knight, queen::Queen, rook::Rook, ChessPiece, ChessPieceTrait,
+    },
+    player::Player,
+};
+use std::fmt;
+
+/*
+    A record of a move that has been made. `piece` is the moving piece as it was before the
+    move, and `captured_piece` is the opposing piece that was removed from the board, if any.
+*/
+#[derive(Debug, Clone)]
+pub struct Move {
+    pub piece: ChessPiece,
+    pub from: BoardPosition,
+    pub to: BoardPosition,
+    pub captured_piece: Option<ChessPiece>,
+    pub flags: MoveFlags,
+}
+
+/*
+    A legal move for the player whose turn it is. A pawn reaching the last row can be promoted
+    to one of four pieces, so each promoting push or capture is listed as four distinct moves.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LegalMove {
+    pub from: BoardPosition,
+    pub to: BoardPosition,
+    pub promotion: Option<PromotionPiece>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MoveFlags {
+    pub castling: Option<CastlingSide>,
+    pub promotion: Option<PromotionPiece>,
+    // Whether a pawn captured an opposing pawn that had just moved two positions forward
+    pub en_passant: bool,
+    // Whether the move puts the opposing king in check
+    pub is_check: bool,
+    pub is_checkmate: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastlingSide {
+    Kingside,
+    Queenside,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromotionPiece {
+    Queen,
+    Rook,
+    Bishop,
+    Knight,
+}
+
+impl PromotionPiece {
+    pub const ALL: [PromotionPiece; 4] = [
+        PromotionPiece::Queen,
+        PromotionPiece::Rook,
+        PromotionPiece::Bishop,
+        PromotionPiece::Knight,
+    ];
+
+    pub fn to_chess_piece(self, player: Player, position: BoardPosition) -> ChessPiece {
+        match self {
+            Self::Queen => ChessPiece::Queen(Queen { player, position }),
+            // A promoted rook has never stood on its starting position, so it can not castle
+            Self::Rook => ChessPiece::Rook(Rook {
+                player,
+                position,
+                has_been_moved: true,
+            }),
+            Self::Bishop => ChessPiece::Bishop(Bishop { player, position }),
+            Self::Knight => ChessPiece::Knight(Knight { player, position }),
+        }
+    }
+
+    // The letter used for the piece in chess notation, e.g. the "Q" in "e8=Q"
+    pub fn get_piece_letter(&self) -> char {
+        match self {
+            Self::Queen => 'Q',
+            Self::Rook => 'R',
+            Self::Bishop => 'B',
+            Self::Knight => 'N',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MoveError {
+    GameIsFinished,
+    NoPieceAtPosition(BoardPosition),
+    NotPlayersPiece {
+        position: BoardPosition,
+        player: Player,
+    },
+    IllegalMove {
+        from: BoardPosition,
+        to: BoardPosition,
+    },
+    MissingPromotionPiece {
+        from: BoardPosition,
+        to: BoardPosition,
+    },
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let player: &Player = self.piece.get_player();
+        match self.flags.castling {
+            Some(CastlingSide::Kingside) => write!(f, "Player {} castles kingside", player)?,
+            Some(CastlingSide::Queenside) => write!(f, "Player {} castles queenside", player)?,
+            None => write!(
+                f,
+                "Player {} moves {} from {} to {}",
+                player,
+                self.piece.get_piece_name(),
+                self.from,
+                self.to
+            )?,
+        }
+        if let Some(promotion) = &self.flags.promotion {
+            write!(f, ", promoting to {}", promotion)?;
+        }
+        if let Some(captured_piece) = &self.captured_piece {
+            write!(f, ", capturing {}", captured_piece.get_piece_name())?;
+            if self.flags.en_passant {
+                write!(f, " en passant")?;
+            }
+        }
+        if self.flags.is_check {
+            write!(f, ", giving check")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PromotionPiece {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Queen => write!(f, "Queen"),
+            Self::Rook => write!(f, "Rook"),
+            Self::Bishop => write!(f, "Bishop"),
+            Self::Knight => write!(f, "Knight"),
+        }
+    }
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GameIsFinished => write!(f, "The game has already finished"),
+            Self::NoPieceAtPosition(position) => write!(f, "There is no piece on {}", position),
+            Self::NotPlayersPiece { position, player } => write!(
+                f,
+                "The piece on {} does not belong to Player {}",
+                position, player
+            ),
+            Self::IllegalMove { from, to } => {
+                write!(f, "Moving from {} to {} is not a legal move", from, to)
+            }
+            Self::MissingPromotionPiece { from, to } => write!(
+                f,
+                "Moving from {} to {} promotes a pawn, so a piece to promote to must be chosen",
+                from, to
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
@@ -0,0 +1,508 @@
+use crate::player::Player;
+use std::{
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+// What a player gets back on their clock for each move they make
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBonus {
+    None,
+    // Fischer increment: added to the clock after every move
+    Increment(Duration),
+    // Bronstein delay: the time spent on the move is given back, up to the delay
+    Delay(Duration),
+}
+
+/*
+    A stretch of the game played at one rate. Each player has to make `moves` moves in `time`,
+    after which the next period's time is added to their clock. A period without a move count
+    lasts for the rest of the game (sudden death).
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeControlPeriod {
+    pub moves: Option<u32>,
+    pub time: Duration,
+    pub bonus: TimeBonus,
+}
+
+/*
+    The periods of a time control, in the order they are played. A last period with a move
+    count starts over once it is completed, so "40/120" gives 120 minutes for every 40 moves.
+
+    Time controls are written the way they are usually announced, with the time in minutes and
+    any bonus in seconds, and periods separated by commas:
+
+        5          5 minutes sudden death
+        3+2        3 minutes with a 2 second Fischer increment
+        15d10      15 minutes with a 10 second Bronstein delay
+        40/90+30, 30+30
+                   90 minutes for the first 40 moves, then 30 more minutes for the rest of
+                   the game, with a 30 second increment throughout
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeControl {
+    periods: Vec<TimeControlPeriod>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeControlError {
+    Empty,
+    InvalidPeriod(String),
+    // Only the last period can last for the rest of the game
+    SuddenDeathBeforeLastPeriod(String),
+}
+
+impl fmt::Display for TimeControlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "The time control has no periods"),
+            Self::InvalidPeriod(period) => write!(
+                f,
+                "\"{}\" is not a time control period, e.g. \"5+3\", \"15d10\" or \"40/90+30\"",
+                period
+            ),
+            Self::SuddenDeathBeforeLastPeriod(period) => write!(
+                f,
+                "\"{}\" lasts for the rest of the game, so it must be the last period",
+                period
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TimeControlError {}
+
+impl FromStr for TimeControlPeriod {
+    type Err = TimeControlError;
+
+    fn from_str(period: &str) -> Result<Self, Self::Err> {
+        let invalid_period = || TimeControlError::InvalidPeriod(period.to_string());
+        let (moves, rest): (Option<u32>, &str) = match period.split_once('/') {
+            Some((moves, rest)) => (
+                Some(
+                    moves
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|moves| *moves > 0)
+                        .ok_or_else(invalid_period)?,
+                ),
+                rest,
+            ),
+            None => (None, period),
+        };
+        let (minutes, bonus): (&str, Option<(char, &str)>) = match rest.find(['+', 'd']) {
+            Some(index) => (
+                &rest[..index],
+                Some((rest.as_bytes()[index] as char, &rest[index + 1..])),
+            ),
+            None => (rest, None),
+        };
+        let parse_seconds = |seconds: &str, factor: f64| -> Result<Duration, TimeControlError> {
+            seconds
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds * factor).ok())
+                .ok_or_else(invalid_period)
+        };
+
+        let time: Duration = parse_seconds(minutes, 60.0)?;
+        if time.is_zero() {
+            return Err(invalid_period());
+        }
+        let bonus: TimeBonus = match bonus {
+            None => TimeBonus::None,
+            Some(('+', seconds)) => TimeBonus::Increment(parse_seconds(seconds, 1.0)?),
+            Some((_, seconds)) => TimeBonus::Delay(parse_seconds(seconds, 1.0)?),
+        };
+        Ok(TimeControlPeriod { moves, time, bonus })
+    }
+}
+
+impl fmt::Display for TimeControlPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(moves) = self.moves {
+            write!(f, "{}/", moves)?;
+        }
+        write!(f, "{}", self.time.as_secs_f64() / 60.0)?;
+        match self.bonus {
+            TimeBonus::None => Ok(()),
+            TimeBonus::Increment(increment) => write!(f, "+{}", increment.as_secs_f64()),
+            TimeBonus::Delay(delay) => write!(f, "d{}", delay.as_secs_f64()),
+        }
+    }
+}
+
+impl FromStr for TimeControl {
+    type Err = TimeControlError;
+
+    fn from_str(time_control: &str) -> Result<Self, Self::Err> {
+        let period_notations: Vec<&str> = time_control
+            .split(',')
+            .map(str::trim)
+            .filter(|period| !period.is_empty())
+            .collect();
+        let periods: Vec<TimeControlPeriod> = period_notations
+            .iter()
+            .map(|period| period.parse())
+            .collect::<Result<_, _>>()?;
+        if periods.is_empty() {
+            return Err(TimeControlError::Empty);
+        }
+        if let Some(index) = periods[..periods.len() - 1]
+            .iter()
+            .position(|period| period.moves.is_none())
+        {
+            return Err(TimeControlError::SuddenDeathBeforeLastPeriod(
+                period_notations[index].to_string(),
+            ));
+        }
+        Ok(TimeControl { periods })
+    }
+}
+
+impl fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let periods: Vec<String> = self
+            .periods
+            .iter()
+            .map(|period| period.to_string())
+            .collect();
+        write!(f, "{}", periods.join(", "))
+    }
+}
+
+impl TimeControl {
+    /*
+        Returns the period that a player's `move_number`th move (counting from 1) is played in,
+        and whether that move is the last one of the period.
+    */
+    fn get_period(&self, move_number: u32) -> (&TimeControlPeriod, bool) {
+        let mut first_move_number: u32 = 1;
+        let mut period_index: usize = 0;
+        loop {
+            let period: &TimeControlPeriod = &self.periods[period_index];
+            let Some(moves) = period.moves else {
+                return (period, false);
+            };
+            // Saturates for periods so long that no game could reach their end
+            let last_move_number: u32 = first_move_number.saturating_add(moves - 1);
+            if move_number <= last_move_number {
+                return (period, move_number == last_move_number);
+            }
+            first_move_number = last_move_number + 1;
+            period_index = (period_index + 1).min(self.periods.len() - 1);
+        }
+    }
+}
+
+/*
+    A clock for each player, only one of which runs at a time. Pressing the clock after a move
+    stops the mover's clock, gives them their bonus (and the next period's time, if the move
+    completed a period) and starts their opponent's.
+
+    Every method takes the current time, so the clock itself never looks at the system time.
+*/
+#[derive(Debug, Clone)]
+pub struct ChessClock {
+    pub time_control: TimeControl,
+    remaining_times: HashMap<Player, Duration>,
+    moves_made: HashMap<Player, u32>,
+    // The player whose clock is running, and when it was last started
+    running: Option<(Player, Instant)>,
+}
+
+impl ChessClock {
+    pub fn new(time_control: TimeControl) -> Self {
+        let initial_time: Duration = time_control.get_period(1).0.time;
+        Self {
+            time_control,
+            remaining_times: HashMap::from([
+                (Player::White, initial_time),
+                (Player::Black, initial_time),
+            ]),
+            moves_made: HashMap::from([(Player::White, 0), (Player::Black, 0)]),
+            running: None,
+        }
+    }
+
+    pub fn start(&mut self, player: Player, now: Instant) {
+        self.stop(now);
+        self.running = Some((player, now));
+    }
+
+    // Stops the running clock without giving a bonus, e.g. when the game finishes
+    pub fn stop(&mut self, now: Instant) {
+        if let Some((player, started_at)) = self.running.take() {
+            let remaining_time: Duration = self.remaining_times[&player]
+                .saturating_sub(now.saturating_duration_since(started_at));
+            self.remaining_times.insert(player, remaining_time);
+        }
+    }
+
+    // Ends the move of the player whose clock is running, returning the time left on their clock
+    pub fn press(&mut self, now: Instant) -> Option<Duration> {
+        let (player, started_at) = self.running?;
+        self.stop(now);
+        let time_spent: Duration = now.saturating_duration_since(started_at);
+        let move_number: u32 = self.moves_made[&player] + 1;
+        self.moves_made.insert(player, move_number);
+
+        let (period, completes_period) = self.time_control.get_period(move_number);
+        let mut time_added: Duration = match period.bonus {
+            TimeBonus::None => Duration::ZERO,
+            TimeBonus::Increment(increment) => increment,
+            TimeBonus::Delay(delay) => time_spent.min(delay),
+        };
+        if completes_period {
+            time_added += self.time_control.get_period(move_number + 1).0.time;
+        }
+        let remaining_time: Duration = self.remaining_times[&player] + time_added;
+        self.remaining_times.insert(player, remaining_time);
+
+        self.running = Some((player.get_opponent(), now));
+        Some(remaining_time)
+    }
+
+    pub fn get_remaining_time(&self, player: &Player, now: Instant) -> Duration {
+        let remaining_time: Duration = self.remaining_times[player];
+        match self.running {
+            Some((running_player, started_at)) if running_player == *player => {
+                remaining_time.saturating_sub(now.saturating_duration_since(started_at))
+            }
+            _ => remaining_time,
+        }
+    }
+
+    pub fn get_running_player(&self) -> Option<Player> {
+        self.running.map(|(player, _)| player)
+    }
+
+    // The player whose clock is running, if it has run out of time
+    pub fn get_flagged_player(&self, now: Instant) -> Option<Player> {
+        self.get_running_player()
+            .filter(|player| self.get_remaining_time(player, now).is_zero())
+    }
+}
+
+// Writes a duration as minutes and seconds, with hours in front once there are any
+pub fn format_duration(duration: Duration) -> String {
+    let seconds: u64 = duration.as_secs();
+    match seconds / 3600 {
+        0 => format!("{:02}:{:02}", seconds / 60, seconds % 60),
+        hours => format!("{}:{:02}:{:02}", hours, seconds / 60 % 60, seconds % 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_time_control {
+        use crate::game::clock::{TimeBonus, TimeControl, TimeControlError, TimeControlPeriod};
+        use std::time::Duration;
+
+        #[test]
+        fn test_parse_single_periods() {
+            let sudden_death: TimeControl = "5".parse().unwrap();
+            assert_eq!(
+                sudden_death.periods,
+                vec![TimeControlPeriod {
+                    moves: None,
+                    time: Duration::from_secs(300),
+                    bonus: TimeBonus::None,
+                }]
+            );
+            let increment: TimeControl = "3+2".parse().unwrap();
+            assert_eq!(
+                increment.periods[0].bonus,
+                TimeBonus::Increment(Duration::from_secs(2))
+            );
+            let delay: TimeControl = "15d10".parse().unwrap();
+            assert_eq!(
+                delay.periods[0].bonus,
+                TimeBonus::Delay(Duration::from_secs(10))
+            );
+            let bullet: TimeControl = "0.5".parse().unwrap();
+            assert_eq!(bullet.periods[0].time, Duration::from_secs(30));
+        }
+
+        #[test]
+        fn test_parse_multiple_periods() {
+            let time_control: TimeControl = "40/90+30, 30+30".parse().unwrap();
+            assert_eq!(
+                time_control.periods,
+                vec![
+                    TimeControlPeriod {
+                        moves: Some(40),
+                        time: Duration::from_secs(90 * 60),
+                        bonus: TimeBonus::Increment(Duration::from_secs(30)),
+                    },
+                    TimeControlPeriod {
+                        moves: None,
+                        time: Duration::from_secs(30 * 60),
+                        bonus: TimeBonus::Increment(Duration::from_secs(30)),
+                    },
+                ]
+            );
+            assert_eq!(time_control.to_string(), "40/90+30, 30+30");
+        }
+
+        #[test]
+        fn test_invalid_time_controls() {
+            assert_eq!("".parse::<TimeControl>(), Err(TimeControlError::Empty));
+            for invalid in ["five", "0", "0/90", "5+x", "-5"] {
+                assert_eq!(
+                    invalid.parse::<TimeControl>(),
+                    Err(TimeControlError::InvalidPeriod(invalid.to_string()))
+                );
+            }
+            assert_eq!(
+                "90, 40/30".parse::<TimeControl>(),
+                Err(TimeControlError::SuddenDeathBeforeLastPeriod(
+                    "90".to_string()
+                ))
+            );
+        }
+
+        #[test]
+        fn test_get_period() {
+            let time_control: TimeControl = "40/90, 20/60, 30".parse().unwrap();
+            assert_eq!(time_control.get_period(1).0.moves, Some(40));
+            assert!(time_control.get_period(40).1);
+            assert_eq!(time_control.get_period(41).0.moves, Some(20));
+            assert!(time_control.get_period(60).1);
+            assert_eq!(time_control.get_period(61).0.moves, None);
+            assert!(!time_control.get_period(200).1);
+
+            // The last period repeats
+            let repeating: TimeControl = "40/120".parse().unwrap();
+            assert!(repeating.get_period(80).1);
+            assert!(!repeating.get_period(81).1);
+
+            let endless: TimeControl = "4294967295/5, 10/5".parse().unwrap();
+            assert_eq!(endless.get_period(u32::MAX).0.moves, Some(u32::MAX));
+            assert!(endless.get_period(u32::MAX).1);
+        }
+    }
+
+    mod test_chess_clock {
+        use crate::{
+            game::clock::{ChessClock, TimeControl},
+            player::Player,
+        };
+        use std::time::{Duration, Instant};
+
+        fn clock(time_control: &str) -> ChessClock {
+            ChessClock::new(time_control.parse::<TimeControl>().unwrap())
+        }
+
+        fn seconds(seconds: u64) -> Duration {
+            Duration::from_secs(seconds)
+        }
+
+        #[test]
+        fn test_only_the_running_clock_counts_down() {
+            let start: Instant = Instant::now();
+            let mut clock: ChessClock = clock("5");
+            clock.start(Player::White, start);
+            assert_eq!(
+                clock.get_remaining_time(&Player::White, start + seconds(10)),
+                seconds(290)
+            );
+            assert_eq!(
+                clock.get_remaining_time(&Player::Black, start + seconds(10)),
+                seconds(300)
+            );
+
+            assert_eq!(clock.press(start + seconds(10)), Some(seconds(290)));
+            assert_eq!(clock.get_running_player(), Some(Player::Black));
+            assert_eq!(
+                clock.get_remaining_time(&Player::Black, start + seconds(25)),
+                seconds(285)
+            );
+        }
+
+        #[test]
+        fn test_fischer_increment_is_added_after_each_move() {
+            let start: Instant = Instant::now();
+            let mut clock: ChessClock = clock("3+2");
+            clock.start(Player::White, start);
+            // The increment is added even for a move made straight away
+            assert_eq!(clock.press(start), Some(seconds(182)));
+            assert_eq!(clock.press(start + seconds(10)), Some(seconds(172)));
+        }
+
+        #[test]
+        fn test_bronstein_delay_gives_back_the_time_spent_up_to_the_delay() {
+            let start: Instant = Instant::now();
+            let mut clock: ChessClock = clock("15d10");
+            clock.start(Player::White, start);
+            assert_eq!(clock.press(start + seconds(4)), Some(seconds(900)));
+            assert_eq!(clock.press(start + seconds(34)), Some(seconds(880)));
+        }
+
+        #[test]
+        fn test_completing_a_period_adds_the_next_periods_time() {
+            let start: Instant = Instant::now();
+            let mut clock: ChessClock = clock("2/90+30, 30+30");
+            clock.start(Player::White, start);
+            let mut now: Instant = start;
+            for _ in 0..3 {
+                now += seconds(60);
+                clock.press(now);
+            }
+            // White has made two moves, completing the first period
+            assert_eq!(
+                clock.get_remaining_time(&Player::White, now),
+                seconds(90 * 60 - 120 + 60 + 30 * 60)
+            );
+            assert_eq!(
+                clock.get_remaining_time(&Player::Black, now),
+                seconds(90 * 60 - 60 + 30)
+            );
+        }
+
+        #[test]
+        fn test_period_with_the_largest_move_count() {
+            let clock: ChessClock = clock("4294967295/5");
+            assert_eq!(
+                clock.get_remaining_time(&Player::White, Instant::now()),
+                seconds(300)
+            );
+        }
+
+        #[test]
+        fn test_flag_fall() {
+            let start: Instant = Instant::now();
+            let mut clock: ChessClock = clock("1");
+            clock.start(Player::White, start);
+            assert_eq!(clock.get_flagged_player(start + seconds(59)), None);
+            assert_eq!(
+                clock.get_flagged_player(start + seconds(60)),
+                Some(Player::White)
+            );
+
+            clock.stop(start + seconds(30));
+            assert_eq!(clock.get_running_player(), None);
+            assert_eq!(clock.get_flagged_player(start + seconds(90)), None);
+            assert_eq!(
+                clock.get_remaining_time(&Player::White, start + seconds(90)),
+                seconds(30)
+            );
+        }
+    }
+
+    mod test_format_duration {
+        use crate::game::clock::format_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_minutes_and_hours() {
+            assert_eq!(format_duration(Duration::from_secs(65)), "01:05");
+            assert_eq!(format_duration(Duration::from_secs(3725)), "1:02:05");
+        }
+    }
+}
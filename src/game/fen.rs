@@ -0,0 +1,676 @@
+use super::{
+    board::{initialize_empty_board, ChessBoard, ChessBoardData},
+    board_position::{BoardPosition, CandidateBoardPosition},
+    chess_move::CastlingSide,
+    ChessGame,
+};
+use crate::{
+    piece::{
+        bishop::Bishop, king::King, knight::Knight, pawn::Pawn, queen::Queen, rook::Rook,
+        ChessPiece,
+    },
+    player::Player,
+};
+use std::fmt;
+
+pub const STARTING_POSITION_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+// The castling rights in the order they are written in a FEN string
+const CASTLING_RIGHTS: [(char, Player, CastlingSide); 4] = [
+    ('K', Player::White, CastlingSide::Kingside),
+    ('Q', Player::White, CastlingSide::Queenside),
+    ('k', Player::Black, CastlingSide::Kingside),
+    ('q', Player::Black, CastlingSide::Queenside),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FenError {
+    WrongFieldCount(usize),
+    WrongRowCount(usize),
+    // A row of the piece placement that does not describe exactly eight positions
+    InvalidRow(String),
+    InvalidPieceLetter(char),
+    WrongKingCount { player: Player, count: usize },
+    InvalidActivePlayer(String),
+    InvalidCastlingRights(String),
+    InvalidEnPassantTarget(String),
+    InvalidHalfmoveClock(String),
+    InvalidFullmoveNumber(String),
+    // The player who just moved can not have left their own king in check
+    OpponentInCheck(Player),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongFieldCount(count) => write!(
+                f,
+                "A FEN string has 6 fields (or 4 without the move counters), but {} were given",
+                count
+            ),
+            Self::WrongRowCount(count) => write!(
+                f,
+                "The piece placement must describe 8 rows, but {} were given",
+                count
+            ),
+            Self::InvalidRow(row) => {
+                write!(f, "The row \"{}\" does not describe 8 positions", row)
+            }
+            Self::InvalidPieceLetter(letter) => write!(f, "'{}' is not a piece letter", letter),
+            Self::WrongKingCount { player, count } => write!(
+                f,
+                "Player {} must have exactly one king, but has {}",
+                player, count
+            ),
+            Self::InvalidActivePlayer(field) => write!(
+                f,
+                "The player to move must be \"w\" or \"b\", not \"{}\"",
+                field
+            ),
+            Self::InvalidCastlingRights(field) => write!(
+                f,
+                "\"{}\" are not valid castling rights for this position",
+                field
+            ),
+            Self::InvalidEnPassantTarget(field) => write!(
+                f,
+                "\"{}\" is not a valid en passant target for this position",
+                field
+            ),
+            Self::InvalidHalfmoveClock(field) => {
+                write!(f, "\"{}\" is not a valid halfmove clock", field)
+            }
+            Self::InvalidFullmoveNumber(field) => {
+                write!(f, "\"{}\" is not a valid fullmove number", field)
+            }
+            Self::OpponentInCheck(player) => write!(
+                f,
+                "Player {} is in check, but it is Player {}'s turn",
+                player,
+                player.get_opponent()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
+
+// White moves on even turns and Black on odd turns
+fn get_turn_offset(player_to_move: &Player) -> u32 {
+    match player_to_move {
+        Player::White => 0,
+        Player::Black => 1,
+    }
+}
+
+impl ChessGame {
+    /*
+        Sets up a game from a position in Forsyth-Edwards Notation: the piece placement, the
+        player to move, castling rights, the en passant target, the halfmove clock and the
+        fullmove number. The two move counters may be left out, in which case they start at
+        0 and 1.
+
+        Pieces do not remember whether they have been moved in FEN, so we work it out instead:
+        a pawn has been moved if it is not on its starting row, and a king or rook has been moved
+        unless the castling rights say otherwise.
+
+        A position where the player who just moved is in check could never have been reached,
+        so it is rejected rather than letting their king be captured.
+    */
+    pub fn from_fen(fen: &str) -> Result<ChessGame, FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() != 6 && fields.len() != 4 {
+            return Err(FenError::WrongFieldCount(fields.len()));
+        }
+
+        let mut board: ChessBoard = parse_piece_placement(fields[0])?;
+        let player_to_move: Player = match fields[1] {
+            "w" => Player::White,
+            "b" => Player::Black,
+            field => return Err(FenError::InvalidActivePlayer(field.to_string())),
+        };
+        apply_castling_rights(&mut board, fields[2])?;
+        let en_passant_target: Option<BoardPosition> =
+            parse_en_passant_target(fields[3], &player_to_move, &board)?;
+        // The fullmove number must leave room for the turn count, which counts moves by either player
+        let (halfmove_clock, turn): (u32, u32) = match fields.get(4..6) {
+            Some([halfmove_clock, fullmove_number]) => (
+                halfmove_clock
+                    .parse()
+                    .map_err(|_| FenError::InvalidHalfmoveClock(halfmove_clock.to_string()))?,
+                fullmove_number
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|fullmove_number| fullmove_number.checked_sub(1))
+                    .and_then(|fullmove_index| fullmove_index.checked_mul(2))
+                    .and_then(|turn| turn.checked_add(get_turn_offset(&player_to_move)))
+                    .ok_or(FenError::InvalidFullmoveNumber(fullmove_number.to_string()))?,
+            ),
+            _ => (0, get_turn_offset(&player_to_move)),
+        };
+
+        let mut game: ChessGame = ChessGame::from_board(board, turn);
+        let opponent: Player = player_to_move.get_opponent();
+        if game.board_data.is_in_check(&opponent) {
+            return Err(FenError::OpponentInCheck(opponent));
+        }
+        game.en_passant_target = en_passant_target;
+        game.halfmove_clock = halfmove_clock;
+        game.position_history = vec![game.get_position_key()];
+        game.starting_fen = game.to_fen();
+        game.update_status();
+        Ok(game)
+    }
+
+    // Describes the current position in Forsyth-Edwards Notation
+    pub fn to_fen(&self) -> String {
+        let piece_placement: Vec<String> = self
+            .board_data
+            .board
+            .iter()
+            .rev()
+            .map(|row| {
+                let mut row_notation: String = String::new();
+                let mut empty_positions: u32 = 0;
+                for piece in row.iter() {
+                    match piece {
+                        Some(piece) => {
+                            if empty_positions > 0 {
+                                row_notation.push_str(&empty_positions.to_string());
+                                empty_positions = 0;
+                            }
+                            row_notation.push(piece.get_piece_letter());
+                        }
+                        None => empty_positions += 1,
+                    }
+                }
+                if empty_positions > 0 {
+                    row_notation.push_str(&empty_positions.to_string());
+                }
+                row_notation
+            })
+            .collect();
+
+        let active_player: &str = match self.get_current_player() {
+            Player::White => "w",
+            Player::Black => "b",
+        };
+        let castling_rights: String = CASTLING_RIGHTS
+            .iter()
+            .filter(|(_, player, castling_side)| {
+                self.board_data.has_castling_right(player, *castling_side)
+            })
+            .map(|(letter, _, _)| *letter)
+            .collect();
+        let en_passant_target: String = self
+            .en_passant_target
+            .map_or("-".to_string(), |position| position.to_string());
+
+        format!(
+            "{} {} {} {} {} {}",
+            piece_placement.join("/"),
+            active_player,
+            if castling_rights.is_empty() {
+                "-"
+            } else {
+                &castling_rights
+            },
+            en_passant_target,
+            self.halfmove_clock,
+            self.turn / 2 + 1
+        )
+    }
+}
+
+// Rows are listed from Black's back row (row index 7) down to White's back row (row index 0)
+fn parse_piece_placement(piece_placement: &str) -> Result<ChessBoard, FenError> {
+    let rows: Vec<&str> = piece_placement.split('/').collect();
+    if rows.len() != 8 {
+        return Err(FenError::WrongRowCount(rows.len()));
+    }
+
+    let mut board: ChessBoard = initialize_empty_board();
+    for (row_notation, row_index) in rows.iter().zip((0..8).rev()) {
+        let mut column_index: i32 = 0;
+        for letter in row_notation.chars() {
+            if let Some(empty_positions) =
+                letter.to_digit(10).filter(|digit| (1..=8).contains(digit))
+            {
+                column_index += empty_positions as i32;
+                continue;
+            }
+
+            let position: BoardPosition = CandidateBoardPosition {
+                row_index,
+                column_index,
+            }
+            .validate_candidate_position(&board)
+            .ok_or(FenError::InvalidRow(row_notation.to_string()))?;
+            board[position.get_row_index()][position.get_column_index()] =
+                Some(piece_from_letter(letter, position)?);
+            column_index += 1;
+        }
+        if column_index != 8 {
+            return Err(FenError::InvalidRow(row_notation.to_string()));
+        }
+    }
+
+    let board_data: ChessBoardData = ChessBoardData::new(board);
+    for player in [Player::White, Player::Black] {
+        let count: usize = board_data
+            .get_player_pieces(&player)
+            .values()
+            .filter(|piece| matches!(piece, ChessPiece::King(_)))
+            .count();
+        if count != 1 {
+            return Err(FenError::WrongKingCount { player, count });
+        }
+    }
+
+    Ok(board_data.board)
+}
+
+fn piece_from_letter(letter: char, position: BoardPosition) -> Result<ChessPiece, FenError> {
+    let player: Player = if letter.is_ascii_uppercase() {
+        Player::White
+    } else {
+        Player::Black
+    };
+    let piece: ChessPiece = match letter.to_ascii_uppercase() {
+        'P' => ChessPiece::Pawn(Pawn {
+            player,
+            position,
+            has_been_moved: position.get_row_index()
+                != match player {
+                    Player::White => 1,
+                    Player::Black => 6,
+                },
+        }),
+        'N' => ChessPiece::Knight(Knight { player, position }),
+        'B' => ChessPiece::Bishop(Bishop { player, position }),
+        'R' => ChessPiece::Rook(Rook {
+            player,
+            position,
+            has_been_moved: true,
+        }),
+        'Q' => ChessPiece::Queen(Queen { player, position }),
+        'K' => ChessPiece::King(King {
+            player,
+            position,
+            has_been_moved: true,
+        }),
+        _ => return Err(FenError::InvalidPieceLetter(letter)),
+    };
+    Ok(piece)
+}
+
+// Marks the king and rook of each castling right as not having been moved
+fn apply_castling_rights(board: &mut ChessBoard, castling_rights: &str) -> Result<(), FenError> {
+    if castling_rights == "-" {
+        return Ok(());
+    }
+
+    for letter in castling_rights.chars() {
+        let (_, player, castling_side) = CASTLING_RIGHTS
+            .iter()
+            .find(|(castling_letter, _, _)| *castling_letter == letter)
+            .ok_or(FenError::InvalidCastlingRights(castling_rights.to_string()))?;
+        let row_index: usize = match player {
+            Player::White => 0,
+            Player::Black => 7,
+        };
+        let rook_column_index: usize = match castling_side {
+            CastlingSide::Kingside => 7,
+            CastlingSide::Queenside => 0,
+        };
+
+        let has_king_and_rook: bool = matches!(
+            &board[row_index][4],
+            Some(ChessPiece::King(king)) if king.player == *player
+        ) && matches!(
+            &board[row_index][rook_column_index],
+            Some(ChessPiece::Rook(rook)) if rook.player == *player
+        );
+        if !has_king_and_rook {
+            return Err(FenError::InvalidCastlingRights(castling_rights.to_string()));
+        }
+
+        for column_index in [4, rook_column_index] {
+            match &mut board[row_index][column_index] {
+                Some(ChessPiece::King(king)) => king.has_been_moved = false,
+                Some(ChessPiece::Rook(rook)) => rook.has_been_moved = false,
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/*
+    The en passant target is the position just behind a pawn of the player not to move, which
+    has only just moved two positions forward.
+*/
+fn parse_en_passant_target(
+    en_passant_target: &str,
+    player_to_move: &Player,
+    board: &ChessBoard,
+) -> Result<Option<BoardPosition>, FenError> {
+    if en_passant_target == "-" {
+        return Ok(None);
+    }
+
+    let (target_row_index, pawn_row_index): (usize, usize) = match player_to_move {
+        Player::White => (5, 4),
+        Player::Black => (2, 3),
+    };
+    CandidateBoardPosition::from_algebraic_notation(en_passant_target)
+        .and_then(|candidate_position| candidate_position.validate_candidate_position(board))
+        .filter(|position| {
+            position.get_row_index() == target_row_index
+                && board[position.get_row_index()][position.get_column_index()].is_none()
+                && matches!(
+                    &board[pawn_row_index][position.get_column_index()],
+                    Some(ChessPiece::Pawn(pawn)) if pawn.player != *player_to_move
+                )
+        })
+        .map(Some)
+        .ok_or(FenError::InvalidEnPassantTarget(
+            en_passant_target.to_string(),
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_from_fen {
+        use crate::{
+            game::{
+                board_position::{BoardPosition, CandidateBoardPosition},
+                fen::STARTING_POSITION_FEN,
+                game_status::{GameResult, GameStatus},
+                ChessGame,
+            },
+            piece::{king::King, ChessPiece, PieceMoveData},
+            player::Player,
+        };
+
+        #[test]
+        fn test_starting_position_matches_new_game() {
+            let game: ChessGame = ChessGame::from_fen(STARTING_POSITION_FEN).unwrap();
+            let new_game: ChessGame = ChessGame::new();
+            assert_eq!(game.turn, 0);
+            assert_eq!(game.halfmove_clock, 0);
+            assert_eq!(game.en_passant_target, None);
+            assert_eq!(game.position_history, new_game.position_history);
+            assert_eq!(game.legal_move_list(&Player::White).len(), 20);
+            assert_eq!(game.board_data.white_pieces.len(), 16);
+            assert_eq!(game.board_data.black_pieces.len(), 16);
+        }
+
+        #[test]
+        fn test_player_to_move_and_move_counters_set_the_turn() {
+            let game: ChessGame = ChessGame::from_fen("4k3/8/8/8/8/8/8/4K2R b - - 17 23").unwrap();
+            assert_eq!(game.get_current_player(), Player::Black);
+            assert_eq!(game.turn, 45);
+            assert_eq!(game.halfmove_clock, 17);
+        }
+
+        #[test]
+        fn test_move_counters_can_be_left_out() {
+            let game: ChessGame = ChessGame::from_fen("4k3/8/8/8/8/8/8/4K2R w K -").unwrap();
+            assert_eq!(game.turn, 0);
+            assert_eq!(game.halfmove_clock, 0);
+        }
+
+        #[test]
+        fn test_castling_rights_decide_which_castling_moves_are_legal() {
+            let game: ChessGame =
+                ChessGame::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w Kq - 0 1").unwrap();
+            let king_position: BoardPosition = CandidateBoardPosition {
+                row_index: 0,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let PieceMoveData { valid_moves, .. } =
+                &game.legal_moves(&Player::White)[&king_position];
+            assert!(valid_moves.contains(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board)
+            ));
+            assert!(!valid_moves.contains(
+                &CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&game.board_data.board)
+            ));
+            assert!(matches!(
+                game.board_data.board[7][4],
+                Some(ChessPiece::King(King {
+                    has_been_moved: false,
+                    ..
+                }))
+            ));
+        }
+
+        #[test]
+        fn test_en_passant_target_allows_en_passant_capture() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2").unwrap();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 4,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert!(game.make_move(&from, &to).unwrap().flags.en_passant);
+        }
+
+        #[test]
+        fn test_pawns_off_their_starting_row_have_been_moved() {
+            let game: ChessGame = ChessGame::from_fen("4k3/8/8/8/8/4P3/3P4/4K3 w - - 0 1").unwrap();
+            let legal_moves = game.legal_moves(&Player::White);
+            let moved_pawn_position: BoardPosition = CandidateBoardPosition {
+                row_index: 2,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let unmoved_pawn_position: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            assert_eq!(legal_moves[&moved_pawn_position].valid_moves.len(), 1);
+            assert_eq!(legal_moves[&unmoved_pawn_position].valid_moves.len(), 2);
+        }
+
+        #[test]
+        fn test_checkmated_position_is_finished() {
+            let game: ChessGame = ChessGame::from_fen("7k/6Q1/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+            assert_eq!(
+                game.status,
+                GameStatus::Finished(GameResult::Checkmate {
+                    winner: Player::White
+                })
+            );
+        }
+    }
+
+    mod test_from_fen_errors {
+        use crate::{
+            game::{fen::FenError, ChessGame},
+            player::Player,
+        };
+
+        fn from_fen_error(fen: &str) -> FenError {
+            ChessGame::from_fen(fen).err().unwrap()
+        }
+
+        #[test]
+        fn test_wrong_field_count() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/8/4K3 w - - 0"),
+                FenError::WrongFieldCount(5)
+            );
+        }
+
+        #[test]
+        fn test_wrong_row_count() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/4K3 w - - 0 1"),
+                FenError::WrongRowCount(7)
+            );
+        }
+
+        #[test]
+        fn test_row_with_too_many_positions() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/5PPPP/4K3 w - - 0 1"),
+                FenError::InvalidRow("5PPPP".to_string())
+            );
+        }
+
+        #[test]
+        fn test_row_with_too_few_positions() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/7/8/8/4K3 w - - 0 1"),
+                FenError::InvalidRow("7".to_string())
+            );
+        }
+
+        #[test]
+        fn test_invalid_piece_letter() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/3X4/8/8/4K3 w - - 0 1"),
+                FenError::InvalidPieceLetter('X')
+            );
+        }
+
+        #[test]
+        fn test_missing_king() {
+            assert_eq!(
+                from_fen_error("8/8/8/8/8/8/8/4K3 w - - 0 1"),
+                FenError::WrongKingCount {
+                    player: Player::Black,
+                    count: 0
+                }
+            );
+        }
+
+        #[test]
+        fn test_invalid_active_player() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/8/4K3 white - - 0 1"),
+                FenError::InvalidActivePlayer("white".to_string())
+            );
+        }
+
+        #[test]
+        fn test_castling_right_without_a_rook() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/8/4K3 w K - 0 1"),
+                FenError::InvalidCastlingRights("K".to_string())
+            );
+        }
+
+        #[test]
+        fn test_unknown_castling_letter() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/8/R3K2R w KX - 0 1"),
+                FenError::InvalidCastlingRights("KX".to_string())
+            );
+        }
+
+        #[test]
+        fn test_en_passant_target_without_a_passed_pawn() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/8/4K3 b - e3 0 1"),
+                FenError::InvalidEnPassantTarget("e3".to_string())
+            );
+        }
+
+        #[test]
+        fn test_en_passant_target_on_the_wrong_row() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/4P3/8/8/4K3 w - e3 0 1"),
+                FenError::InvalidEnPassantTarget("e3".to_string())
+            );
+        }
+
+        #[test]
+        fn test_invalid_move_counters() {
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/8/4K3 w - - -1 1"),
+                FenError::InvalidHalfmoveClock("-1".to_string())
+            );
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/8/4K3 w - - 0 0"),
+                FenError::InvalidFullmoveNumber("0".to_string())
+            );
+            assert_eq!(
+                from_fen_error("4k3/8/8/8/8/8/8/4K3 w - - 0 4294967295"),
+                FenError::InvalidFullmoveNumber("4294967295".to_string())
+            );
+        }
+
+        #[test]
+        fn test_player_who_just_moved_is_in_check() {
+            assert_eq!(
+                from_fen_error("4k3/4R3/8/8/8/8/8/4K3 w - - 0 1"),
+                FenError::OpponentInCheck(Player::Black)
+            );
+        }
+    }
+
+    mod test_to_fen {
+        use crate::game::{
+            board_position::{BoardPosition, CandidateBoardPosition},
+            fen::STARTING_POSITION_FEN,
+            ChessGame,
+        };
+
+        #[test]
+        fn test_new_game_is_the_starting_position() {
+            assert_eq!(ChessGame::new().to_fen(), STARTING_POSITION_FEN);
+        }
+
+        #[test]
+        fn test_fen_after_a_double_pawn_push() {
+            let mut game: ChessGame = ChessGame::new();
+            let from: BoardPosition = CandidateBoardPosition {
+                row_index: 1,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            let to: BoardPosition = CandidateBoardPosition {
+                row_index: 3,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&game.board_data.board);
+            game.make_move(&from, &to).unwrap();
+            assert_eq!(
+                game.to_fen(),
+                "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+            );
+        }
+
+        #[test]
+        fn test_fen_round_trips() {
+            for fen in [
+                "r3k2r/8/8/8/8/8/8/R3K2R w Kq - 12 40",
+                "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+                "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2",
+                "8/8/8/8/8/5k2/8/5K2 b - - 99 70",
+            ] {
+                assert_eq!(ChessGame::from_fen(fen).unwrap().to_fen(), fen);
+            }
+        }
+    }
+}
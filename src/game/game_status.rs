@@ -0,0 +1,89 @@
+use crate::player::Player;
+use std::fmt;
+
+/*
+    A game that is still being played is either `Ongoing`, or `DrawClaimable` when the fifty-move
+    rule or threefold repetition allows the player to move to claim a draw. The game carries on
+    unless they do.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    Ongoing,
+    DrawClaimable(DrawReason),
+    Finished(GameResult),
+}
+
+impl GameStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Finished(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameResult {
+    Checkmate { winner: Player },
+    Stalemate,
+    Draw(DrawReason),
+    Resignation { winner: Player },
+    // The loser's clock ran out while their opponent could still checkmate
+    Timeout { winner: Player },
+    // Taken from a game record, which says who won (if anyone) but not how
+    Recorded { winner: Option<Player> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawReason {
+    Agreement,
+    // Claimable once fifty moves by each player pass without a capture or pawn move
+    FiftyMoveRule,
+    // Automatic once seventy-five moves by each player pass without a capture or pawn move
+    SeventyFiveMoveRule,
+    ThreefoldRepetition,
+    FivefoldRepetition,
+    // Neither player has the pieces left to deliver checkmate
+    InsufficientMaterial,
+    // A player ran out of time, but their opponent had no way left to checkmate them
+    TimeoutVsInsufficientMaterial,
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Checkmate { winner } => write!(f, "Checkmate! Player {} wins", winner),
+            Self::Stalemate => write!(f, "Stalemate! The game is a draw"),
+            Self::Draw(reason) => write!(f, "The game is a draw by {}", reason),
+            Self::Resignation { winner } => write!(
+                f,
+                "Player {} resigns. Player {} wins",
+                winner.get_opponent(),
+                winner
+            ),
+            Self::Timeout { winner } => write!(
+                f,
+                "Player {} ran out of time. Player {} wins",
+                winner.get_opponent(),
+                winner
+            ),
+            Self::Recorded {
+                winner: Some(winner),
+            } => write!(f, "Player {} wins", winner),
+            Self::Recorded { winner: None } => write!(f, "The game is a draw"),
+        }
+    }
+}
+
+impl fmt::Display for DrawReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Agreement => write!(f, "agreement"),
+            Self::FiftyMoveRule => write!(f, "the fifty-move rule"),
+            Self::SeventyFiveMoveRule => write!(f, "the seventy-five-move rule"),
+            Self::ThreefoldRepetition => write!(f, "threefold repetition"),
+            Self::FivefoldRepetition => write!(f, "fivefold repetition"),
+            Self::InsufficientMaterial => write!(f, "insufficient material"),
+            Self::TimeoutVsInsufficientMaterial => {
+                write!(f, "timeout against insufficient material")
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use super::{
+    board::ChessBoardData,
+    board_position::BoardPosition,
+    chess_move::Move,
+    game_status::{GameResult, GameStatus},
+};
+use std::time::Duration;
+
+/*
+    An entry in the move history of a ChessGame. Alongside the move itself, we keep a copy of
+    the game state from just before the move was made, so that undoing the move restores it
+    exactly (including captured pieces and `has_been_moved` flags) rather than trying to
+    reverse each of the move's side effects.
+*/
+#[derive(Clone)]
+pub struct MoveHistoryEntry {
+    pub chess_move: Move,
+    pub board_data: ChessBoardData,
+    pub turn: u32,
+    pub status: GameStatus,
+    pub en_passant_target: Option<BoardPosition>,
+    pub halfmove_clock: u32,
+    // A comment on the move, included when the game is exported to PGN
+    pub comment: Option<String>,
+    // The time left on the mover's clock after the move, in a timed game
+    pub remaining_time: Option<Duration>,
+    // The result a game record gives for the game after the move, if it ended there
+    pub recorded_result: Option<GameResult>,
+}
@@ -0,0 +1,1019 @@
+use super::{
+    fen::{FenError, STARTING_POSITION_FEN},
+    game_status::{GameResult, GameStatus},
+    san::SanError,
+    ChessGame,
+};
+use crate::player::Player;
+use std::{fmt, iter::Peekable, mem, time::Duration, vec::IntoIter};
+
+// PGN export format keeps movetext lines below 80 characters
+const MAX_LINE_LENGTH: usize = 79;
+
+/*
+    The tags of the Seven Tag Roster, apart from the result which comes from the game itself.
+    Unknown values are written as "?" (or "????.??.??" for the date), as the PGN standard asks.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgnTags {
+    pub event: String,
+    pub site: String,
+    pub date: String,
+    pub round: String,
+    pub white: String,
+    pub black: String,
+}
+
+/*
+    A game read from PGN. `moves` is the main line, and each move keeps the alternatives to it
+    that were given as variations. Nothing has been checked against the rules of chess yet;
+    `replay` does that.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgnGame {
+    // Every tag pair, in the order they were given
+    pub tags: Vec<(String, String)>,
+    pub moves: Vec<PgnMove>,
+    // The result token at the end of the movetext, e.g. "1-0" or "*"
+    pub result: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgnMove {
+    pub san: String,
+    // Numeric Annotation Glyphs, e.g. 1 for "$1" (or "!")
+    pub nags: Vec<u8>,
+    // Comments given just before (only at the start of a line) or just after the move
+    pub comments: Vec<String>,
+    // Lines of moves played instead of this one
+    pub variations: Vec<Vec<PgnMove>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgnError {
+    InvalidTag(String),
+    UnterminatedComment,
+    UnterminatedVariation,
+    UnexpectedToken(String),
+    InvalidFen(FenError),
+    // `ply` counts moves by either player from the start of the game, starting at 1
+    InvalidMove {
+        ply: u32,
+        token: String,
+        error: SanError,
+    },
+}
+
+impl fmt::Display for PgnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTag(tag) => write!(f, "\"[{}\" is not a valid tag pair", tag),
+            Self::UnterminatedComment => write!(f, "A comment is missing its closing \"}}\""),
+            Self::UnterminatedVariation => {
+                write!(f, "A variation is missing its closing \")\"")
+            }
+            Self::UnexpectedToken(token) => write!(f, "Did not expect \"{}\" here", token),
+            Self::InvalidFen(err) => write!(f, "The FEN tag is invalid: {}", err),
+            Self::InvalidMove { ply, token, error } => write!(
+                f,
+                "Ply {} ({}{} {}): {}",
+                ply,
+                ply.div_ceil(2),
+                if ply % 2 == 1 { "." } else { "..." },
+                token,
+                error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PgnError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PgnToken {
+    Tag { name: String, value: String },
+    Comment(String),
+    Nag(u8),
+    VariationStart,
+    VariationEnd,
+    Result(String),
+    Move(String),
+}
+
+impl Default for PgnTags {
+    fn default() -> Self {
+        Self {
+            event: "?".to_string(),
+            site: "?".to_string(),
+            date: "????.??.??".to_string(),
+            round: "?".to_string(),
+            white: "?".to_string(),
+            black: "?".to_string(),
+        }
+    }
+}
+
+impl ChessGame {
+    /*
+        Exports the game in Portable Game Notation: the Seven Tag Roster, followed by every move
+        in the move history in SAN along with any comments on them, and the result. A game that
+        did not start from the starting position also gets "SetUp" and "FEN" tags.
+
+        SAN depends on the position each move was played in, so each move is written against
+        the copy of that position kept in its history entry.
+    */
+    pub fn to_pgn(&self, tags: &PgnTags) -> String {
+        let result: &str = get_result_token(&self.status);
+        let mut tag_pairs: Vec<(&str, &str)> = vec![
+            ("Event", &tags.event),
+            ("Site", &tags.site),
+            ("Date", &tags.date),
+            ("Round", &tags.round),
+            ("White", &tags.white),
+            ("Black", &tags.black),
+            ("Result", result),
+        ];
+        if self.starting_fen != STARTING_POSITION_FEN {
+            tag_pairs.push(("SetUp", "1"));
+            tag_pairs.push(("FEN", &self.starting_fen));
+        }
+
+        let mut pgn: String = String::new();
+        for (name, value) in tag_pairs {
+            pgn.push_str(&format!(
+                "[{} \"{}\"]\n",
+                name,
+                value.replace('\\', "\\\\").replace('"', "\\\"")
+            ));
+        }
+        pgn.push('\n');
+        pgn.push_str(&wrap_movetext(&self.get_movetext_tokens(result)));
+        pgn.push('\n');
+        pgn
+    }
+
+    fn get_movetext_tokens(&self, result: &str) -> Vec<String> {
+        let mut tokens: Vec<String> = vec![];
+        // Black's moves only get a move number ("1...") when they do not directly follow White's
+        let mut needs_move_number: bool = true;
+
+        for (history_entry, san) in self.move_history.iter().zip(self.get_san_move_list()) {
+            // `turn` is the turn the move was made on
+            let fullmove_number: u32 = history_entry.turn / 2 + 1;
+            match history_entry.turn % 2 {
+                0 => tokens.push(format!("{}.", fullmove_number)),
+                _ if needs_move_number => tokens.push(format!("{}...", fullmove_number)),
+                _ => {}
+            }
+            tokens.push(san);
+            needs_move_number = false;
+
+            // The clock goes first in the comment, as a "[%clk 1:30:00]" command
+            let comment_parts: Vec<String> = history_entry
+                .remaining_time
+                .map(|remaining_time| {
+                    format!("[%clk {}]", format_clock_command_time(remaining_time))
+                })
+                .into_iter()
+                .chain(history_entry.comment.clone())
+                .collect();
+            if !comment_parts.is_empty() {
+                // A comment can not contain its own closing brace
+                tokens.push(format!("{{{}}}", comment_parts.join(" ").replace('}', "")));
+                needs_move_number = true;
+            }
+        }
+
+        tokens.push(result.to_string());
+        tokens
+    }
+}
+
+fn get_result_token(status: &GameStatus) -> &'static str {
+    match status {
+        GameStatus::Finished(
+            GameResult::Checkmate { winner }
+            | GameResult::Resignation { winner }
+            | GameResult::Timeout { winner }
+            | GameResult::Recorded {
+                winner: Some(winner),
+            },
+        ) => match winner {
+            Player::White => "1-0",
+            Player::Black => "0-1",
+        },
+        GameStatus::Finished(
+            GameResult::Stalemate | GameResult::Draw(_) | GameResult::Recorded { winner: None },
+        ) => "1/2-1/2",
+        GameStatus::Ongoing | GameStatus::DrawClaimable(_) => "*",
+    }
+}
+
+// Writes the time on a clock as hours, minutes and seconds, e.g. "0:04:59"
+fn format_clock_command_time(time: Duration) -> String {
+    let seconds: u64 = time.as_secs();
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/*
+    Takes a "[%clk 0:04:59]" command out of a comment, returning the time on the clock along
+    with the rest of the comment. Fractions of a second are allowed, e.g. "[%clk 0:00:09.5]".
+*/
+fn take_clock_command(comment: &str) -> (Option<Duration>, String) {
+    let Some(start) = comment.find("[%clk ") else {
+        return (None, comment.to_string());
+    };
+    let Some(length) = comment[start..].find(']') else {
+        return (None, comment.to_string());
+    };
+    let time: Option<Duration> = match comment[start + "[%clk ".len()..start + length]
+        .trim()
+        .split(':')
+        .collect::<Vec<&str>>()
+        .as_slice()
+    {
+        [hours, minutes, seconds] => {
+            match (
+                hours.parse::<u64>(),
+                minutes.parse::<u64>(),
+                seconds.parse::<f64>(),
+            ) {
+                // Times too long to add up are treated like any other malformed time
+                (Ok(hours), Ok(minutes), Ok(seconds)) => hours
+                    .checked_mul(3600)
+                    .zip(minutes.checked_mul(60))
+                    .and_then(|(hours, minutes)| hours.checked_add(minutes))
+                    .and_then(|whole_seconds| {
+                        Duration::try_from_secs_f64(whole_seconds as f64 + seconds).ok()
+                    }),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    match time {
+        Some(time) => {
+            let rest: String = format!(
+                "{} {}",
+                comment[..start].trim(),
+                comment[start + length + 1..].trim()
+            );
+            (Some(time), rest.trim().to_string())
+        }
+        None => (None, comment.to_string()),
+    }
+}
+
+// Joins the tokens with spaces, starting a new line whenever a line would grow too long
+fn wrap_movetext(tokens: &[String]) -> String {
+    let mut lines: Vec<String> = vec![];
+    let mut line: String = String::new();
+    for word in tokens.iter().flat_map(|token| token.split_whitespace()) {
+        if !line.is_empty() && line.len() + 1 + word.len() > MAX_LINE_LENGTH {
+            lines.push(line);
+            line = String::new();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    lines.push(line);
+    lines.join("\n")
+}
+
+/*
+    Reads every game in `pgn`, which may hold any number of games one after another. Each game
+    is a section of tag pairs followed by its movetext, in which move numbers are skipped and
+    comments, NAGs and (nested) variations are kept alongside the moves they belong to.
+*/
+pub fn parse_pgn(pgn: &str) -> Result<Vec<PgnGame>, PgnError> {
+    let mut tokens: Peekable<IntoIter<PgnToken>> = tokenize(pgn)?.into_iter().peekable();
+    let mut games: Vec<PgnGame> = vec![];
+
+    while tokens.peek().is_some() {
+        let mut tags: Vec<(String, String)> = vec![];
+        while let Some(PgnToken::Tag { .. }) = tokens.peek() {
+            if let Some(PgnToken::Tag { name, value }) = tokens.next() {
+                tags.push((name, value));
+            }
+        }
+        let moves: Vec<PgnMove> = parse_line(&mut tokens, false)?;
+        let result: Option<String> = match tokens.peek() {
+            Some(PgnToken::Result(_)) => match tokens.next() {
+                Some(PgnToken::Result(result)) => Some(result),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if !tags.is_empty() || !moves.is_empty() || result.is_some() {
+            games.push(PgnGame {
+                tags,
+                moves,
+                result,
+            });
+        }
+    }
+
+    Ok(games)
+}
+
+impl PgnGame {
+    pub fn get_tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag_name, _)| tag_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /*
+        Plays the main line onto a new ChessGame (set up from the "FEN" tag, if there is one),
+        with each move's comments attached to it, and the time left on the clock from any
+        "%clk" command in them. Every move, including those in variations, must be a single
+        legal move in the position it is played in.
+
+        The returned game is at the end of the main line, finished if the result token says
+        so; `undo_move` and `redo_move` step back and forth through it.
+    */
+    pub fn replay(&self) -> Result<ChessGame, PgnError> {
+        let mut game: ChessGame = match self.get_tag("FEN") {
+            Some(fen) => ChessGame::from_fen(fen).map_err(PgnError::InvalidFen)?,
+            None => ChessGame::new(),
+        };
+        replay_line(&mut game, &self.moves)?;
+        self.apply_result(&mut game);
+        Ok(game)
+    }
+
+    /*
+        A game that was decided without the position on the board showing it ends the way the
+        result token says. The token does not say how the game ended, so apart from a draw that
+        could be claimed, the result only records who won.
+    */
+    fn apply_result(&self, game: &mut ChessGame) {
+        if game.status.is_finished() {
+            return;
+        }
+        let result: GameResult = match (self.result.as_deref(), game.status) {
+            (Some("1-0"), _) => GameResult::Recorded {
+                winner: Some(Player::White),
+            },
+            (Some("0-1"), _) => GameResult::Recorded {
+                winner: Some(Player::Black),
+            },
+            (Some("1/2-1/2"), GameStatus::DrawClaimable(reason)) => GameResult::Draw(reason),
+            (Some("1/2-1/2"), _) => GameResult::Recorded { winner: None },
+            _ => return,
+        };
+        game.record_result(result);
+    }
+}
+
+fn replay_line(game: &mut ChessGame, moves: &[PgnMove]) -> Result<(), PgnError> {
+    for pgn_move in moves {
+        // Variations are alternatives to this move, so they start from the position before it
+        let fen_before_move: Option<String> =
+            (!pgn_move.variations.is_empty()).then(|| game.to_fen());
+        let ply: u32 = game.turn + 1;
+        let invalid_move = |error: SanError| PgnError::InvalidMove {
+            ply,
+            token: pgn_move.san.clone(),
+            error,
+        };
+
+        let legal_move = game.parse_san(&pgn_move.san).map_err(invalid_move)?;
+        // The only way a legal move can fail is if the game has already finished
+        game.make_move_with_promotion(&legal_move.from, &legal_move.to, legal_move.promotion)
+            .map_err(|_| invalid_move(SanError::IllegalMove(pgn_move.san.clone())))?;
+        let mut comments: Vec<String> = vec![];
+        for comment in pgn_move.comments.iter() {
+            let (remaining_time, comment) = take_clock_command(comment);
+            if let (Some(remaining_time), Some(history_entry)) =
+                (remaining_time, game.move_history.last_mut())
+            {
+                history_entry.remaining_time = Some(remaining_time);
+            }
+            if !comment.is_empty() {
+                comments.push(comment);
+            }
+        }
+        if !comments.is_empty() {
+            game.comment_on_last_move(comments.join(" "));
+        }
+
+        if let Some(fen_before_move) = fen_before_move {
+            for variation in pgn_move.variations.iter() {
+                let mut variation_game: ChessGame = ChessGame::from_fen(&fen_before_move)
+                    .unwrap_or_else(|err| panic!("Failed to set up {}: {}", fen_before_move, err));
+                replay_line(&mut variation_game, variation)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Reads moves, with their comments, NAGs and variations, until the end of the line
+fn parse_line(
+    tokens: &mut Peekable<IntoIter<PgnToken>>,
+    is_variation: bool,
+) -> Result<Vec<PgnMove>, PgnError> {
+    let mut moves: Vec<PgnMove> = vec![];
+    let mut leading_comments: Vec<String> = vec![];
+
+    loop {
+        match tokens.peek() {
+            None | Some(PgnToken::Tag { .. }) | Some(PgnToken::Result(_)) => {
+                if is_variation {
+                    return Err(PgnError::UnterminatedVariation);
+                }
+                break;
+            }
+            Some(PgnToken::VariationEnd) => {
+                if !is_variation {
+                    return Err(PgnError::UnexpectedToken(")".to_string()));
+                }
+                tokens.next();
+                break;
+            }
+            _ => {}
+        }
+
+        match tokens.next() {
+            Some(PgnToken::Move(san)) => moves.push(PgnMove {
+                san,
+                nags: vec![],
+                comments: mem::take(&mut leading_comments),
+                variations: vec![],
+            }),
+            Some(PgnToken::Comment(comment)) => match moves.last_mut() {
+                Some(last_move) => last_move.comments.push(comment),
+                None => leading_comments.push(comment),
+            },
+            Some(PgnToken::Nag(nag)) => moves
+                .last_mut()
+                .ok_or(PgnError::UnexpectedToken(format!("${}", nag)))?
+                .nags
+                .push(nag),
+            Some(PgnToken::VariationStart) => {
+                let variation: Vec<PgnMove> = parse_line(tokens, true)?;
+                moves
+                    .last_mut()
+                    .ok_or(PgnError::UnexpectedToken("(".to_string()))?
+                    .variations
+                    .push(variation);
+            }
+            _ => unreachable!("The end of a line is handled above"),
+        }
+    }
+
+    Ok(moves)
+}
+
+fn tokenize(pgn: &str) -> Result<Vec<PgnToken>, PgnError> {
+    let mut tokens: Vec<PgnToken> = vec![];
+    let mut chars = pgn.chars().peekable();
+    let mut is_line_start: bool = true;
+
+    while let Some(char) = chars.next() {
+        let was_line_start: bool = is_line_start;
+        is_line_start = char == '\n';
+        match char {
+            _ if char.is_whitespace() => {}
+            // A line starting with "%" is an escape for other programs, which we skip
+            '%' if was_line_start => {
+                chars.by_ref().find(|char| *char == '\n');
+                is_line_start = true;
+            }
+            '[' => {
+                let mut tag: String = String::new();
+                let mut is_in_value: bool = false;
+                loop {
+                    match chars.next() {
+                        Some(']') if !is_in_value => break,
+                        Some('\\') if is_in_value => {
+                            tag.push('\\');
+                            tag.extend(chars.next());
+                        }
+                        Some(char) => {
+                            is_in_value ^= char == '"';
+                            tag.push(char);
+                        }
+                        None => return Err(PgnError::InvalidTag(tag)),
+                    }
+                }
+                tokens.push(parse_tag(&tag).ok_or(PgnError::InvalidTag(tag))?);
+            }
+            '{' => {
+                let mut comment: String = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(char) => comment.push(char),
+                        None => return Err(PgnError::UnterminatedComment),
+                    }
+                }
+                tokens.push(PgnToken::Comment(comment.trim().to_string()));
+            }
+            ';' => {
+                let comment: String = chars.by_ref().take_while(|char| *char != '\n').collect();
+                tokens.push(PgnToken::Comment(comment.trim().to_string()));
+                is_line_start = true;
+            }
+            '(' => tokens.push(PgnToken::VariationStart),
+            ')' => tokens.push(PgnToken::VariationEnd),
+            '$' => {
+                let mut digits: String = String::new();
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(digit);
+                }
+                let nag: u8 = digits
+                    .parse()
+                    .map_err(|_| PgnError::UnexpectedToken(format!("${}", digits)))?;
+                tokens.push(PgnToken::Nag(nag));
+            }
+            _ => {
+                let mut symbol: String = char.to_string();
+                while let Some(char) =
+                    chars.next_if(|char| !char.is_whitespace() && !"[]{}();$".contains(*char))
+                {
+                    symbol.push(char);
+                }
+                tokens.extend(tokenize_symbol(&symbol)?);
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+// Reads the inside of a tag pair, e.g. `Event "Casual game"`
+fn parse_tag(tag: &str) -> Option<PgnToken> {
+    let (name, value) = tag.trim().split_once(char::is_whitespace)?;
+    let value: &str = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut unescaped_value: String = String::new();
+    let mut chars = value.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => unescaped_value.push(chars.next()?),
+            _ => unescaped_value.push(char),
+        }
+    }
+    Some(PgnToken::Tag {
+        name: name.to_string(),
+        value: unescaped_value,
+    })
+}
+
+/*
+    Anything else in the movetext is a result, a move number (which we skip, as they can be
+    worked out from the moves) or a move. Moves may have a move number in front, as in "1.e4",
+    and a suffix annotation such as "!?" behind, which stands for one of the first six NAGs.
+*/
+fn tokenize_symbol(symbol: &str) -> Result<Vec<PgnToken>, PgnError> {
+    const SUFFIX_ANNOTATIONS: [&str; 6] = ["!", "?", "!!", "??", "!?", "?!"];
+
+    if ["1-0", "0-1", "1/2-1/2", "*"].contains(&symbol) {
+        return Ok(vec![PgnToken::Result(symbol.to_string())]);
+    }
+
+    let without_move_number: &str = symbol.trim_start_matches(|char: char| char.is_ascii_digit());
+    let san: &str = if without_move_number.is_empty() {
+        ""
+    } else if without_move_number.starts_with('.') {
+        without_move_number.trim_start_matches('.')
+    } else {
+        symbol
+    };
+    let annotation: &str = &san[san.trim_end_matches(['!', '?']).len()..];
+    let san: &str = &san[..san.len() - annotation.len()];
+
+    let mut tokens: Vec<PgnToken> = vec![];
+    if !san.is_empty() {
+        tokens.push(PgnToken::Move(san.to_string()));
+    }
+    if !annotation.is_empty() {
+        let nag: usize = SUFFIX_ANNOTATIONS
+            .iter()
+            .position(|suffix_annotation| *suffix_annotation == annotation)
+            .ok_or(PgnError::UnexpectedToken(symbol.to_string()))?;
+        tokens.push(PgnToken::Nag(nag as u8 + 1));
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_to_pgn {
+        use crate::{
+            game::{
+                board::initialize_empty_board, board_position::get_position, pgn::PgnTags,
+                ChessGame,
+            },
+            player::Player,
+        };
+        use std::time::Duration;
+
+        fn play(game: &mut ChessGame, moves: &[(&str, &str)]) {
+            for (from, to) in moves {
+                game.make_move(&get_position(from), &get_position(to))
+                    .unwrap();
+            }
+        }
+
+        #[test]
+        fn test_scholars_mate() {
+            let mut game: ChessGame = ChessGame::new();
+            play(
+                &mut game,
+                &[
+                    ("e2", "e4"),
+                    ("e7", "e5"),
+                    ("f1", "c4"),
+                    ("b8", "c6"),
+                    ("d1", "h5"),
+                    ("g8", "f6"),
+                    ("h5", "f7"),
+                ],
+            );
+            let tags: PgnTags = PgnTags {
+                event: "Casual game".to_string(),
+                white: "Alice".to_string(),
+                black: "Bob".to_string(),
+                ..PgnTags::default()
+            };
+            assert_eq!(
+                game.to_pgn(&tags),
+                "[Event \"Casual game\"]\n\
+                 [Site \"?\"]\n\
+                 [Date \"????.??.??\"]\n\
+                 [Round \"?\"]\n\
+                 [White \"Alice\"]\n\
+                 [Black \"Bob\"]\n\
+                 [Result \"1-0\"]\n\
+                 \n\
+                 1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0\n"
+            );
+        }
+
+        #[test]
+        fn test_unfinished_game_without_moves() {
+            let game: ChessGame = ChessGame::new();
+            assert!(game
+                .to_pgn(&PgnTags::default())
+                .ends_with("[Result \"*\"]\n\n*\n"));
+        }
+
+        #[test]
+        fn test_game_set_up_from_a_board_without_kings() {
+            let game: ChessGame = ChessGame::from_board(initialize_empty_board(), 0);
+            assert!(game
+                .to_pgn(&PgnTags::default())
+                .ends_with("[FEN \"8/8/8/8/8/8/8/8 w - - 0 1\"]\n\n*\n"));
+        }
+
+        #[test]
+        fn test_comments_and_black_move_numbers() {
+            let mut game: ChessGame = ChessGame::new();
+            play(&mut game, &[("e2", "e4")]);
+            game.comment_on_last_move("Best by test".to_string());
+            play(&mut game, &[("c7", "c5"), ("g1", "f3")]);
+            game.resign(&Player::Black);
+            assert!(game
+                .to_pgn(&PgnTags::default())
+                .ends_with("\n\n1. e4 {Best by test} 1... c5 2. Nf3 1-0\n"));
+        }
+
+        #[test]
+        fn test_remaining_time_is_written_as_a_clock_command() {
+            let mut game: ChessGame = ChessGame::new();
+            play(&mut game, &[("e2", "e4"), ("e7", "e5")]);
+            game.move_history[0].remaining_time = Some(Duration::from_secs(299));
+            game.move_history[1].remaining_time = Some(Duration::from_millis(3_723_900));
+            game.comment_on_last_move("Symmetrical".to_string());
+            assert!(game
+                .to_pgn(&PgnTags::default())
+                .ends_with("\n\n1. e4 {[%clk 0:04:59]} 1... e5 {[%clk 1:02:03] Symmetrical} *\n"));
+        }
+
+        #[test]
+        fn test_game_from_a_fen_position() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("4k3/8/8/8/8/8/8/R3K3 b Q - 0 30").unwrap();
+            play(&mut game, &[("e8", "d7"), ("e1", "c1")]);
+            game.agree_draw();
+            let pgn: String = game.to_pgn(&PgnTags::default());
+            assert!(pgn.contains(
+                "[Result \"1/2-1/2\"]\n\
+                 [SetUp \"1\"]\n\
+                 [FEN \"4k3/8/8/8/8/8/8/R3K3 b Q - 0 30\"]\n\n"
+            ));
+            assert!(pgn.ends_with("\n\n30... Kd7 31. O-O-O+ 1/2-1/2\n"));
+        }
+
+        #[test]
+        fn test_tag_values_are_escaped() {
+            let game: ChessGame = ChessGame::new();
+            let tags: PgnTags = PgnTags {
+                white: "Jan \"The Rook\" Smith\\".to_string(),
+                ..PgnTags::default()
+            };
+            assert!(game
+                .to_pgn(&tags)
+                .contains("[White \"Jan \\\"The Rook\\\" Smith\\\\\"]\n"));
+        }
+
+        #[test]
+        fn test_long_movetext_is_wrapped() {
+            let mut game: ChessGame = ChessGame::new();
+            // Ends in a draw by fivefold repetition after eight moves by each player
+            for _ in 0..4 {
+                play(
+                    &mut game,
+                    &[("g1", "f3"), ("g8", "f6"), ("f3", "g1"), ("f6", "g8")],
+                );
+            }
+            let pgn: String = game.to_pgn(&PgnTags::default());
+            let movetext: &str = pgn.split("\n\n").nth(1).unwrap();
+            assert!(movetext.lines().count() > 1);
+            assert!(movetext.lines().all(|line| line.len() < 80));
+        }
+    }
+
+    mod test_parse_pgn {
+        use crate::game::pgn::{parse_pgn, PgnError, PgnGame, PgnMove};
+
+        fn pgn_move(san: &str) -> PgnMove {
+            PgnMove {
+                san: san.to_string(),
+                nags: vec![],
+                comments: vec![],
+                variations: vec![],
+            }
+        }
+
+        #[test]
+        fn test_tags_and_moves() {
+            let games: Vec<PgnGame> = parse_pgn(
+                "[Event \"Casual \\\"blitz\\\"\"]\n[White \"Alice\"]\n\n1. e4 e5 2.Nf3 1-0\n",
+            )
+            .unwrap();
+            assert_eq!(
+                games,
+                vec![PgnGame {
+                    tags: vec![
+                        ("Event".to_string(), "Casual \"blitz\"".to_string()),
+                        ("White".to_string(), "Alice".to_string()),
+                    ],
+                    moves: vec![pgn_move("e4"), pgn_move("e5"), pgn_move("Nf3")],
+                    result: Some("1-0".to_string()),
+                }]
+            );
+            assert_eq!(games[0].get_tag("White"), Some("Alice"));
+            assert_eq!(games[0].get_tag("Black"), None);
+        }
+
+        #[test]
+        fn test_comments_and_nags() {
+            let games: Vec<PgnGame> = parse_pgn(
+                "{Opening} 1. e4 $1 {Best by test} e5?! ; A rest of line comment\n% skipped\n*",
+            )
+            .unwrap();
+            let moves: &Vec<PgnMove> = &games[0].moves;
+            assert_eq!(moves.len(), 2);
+            assert_eq!(moves[0].comments, vec!["Opening", "Best by test"]);
+            assert_eq!(moves[0].nags, vec![1]);
+            assert_eq!(moves[1].san, "e5");
+            assert_eq!(moves[1].nags, vec![6]);
+            assert_eq!(moves[1].comments, vec!["A rest of line comment"]);
+        }
+
+        #[test]
+        fn test_nested_variations() {
+            let games: Vec<PgnGame> =
+                parse_pgn("1. e4 (1. d4 d5 (1... Nf6 2. c4)) (1. c4) e5 *").unwrap();
+            let variations: &Vec<Vec<PgnMove>> = &games[0].moves[0].variations;
+            assert_eq!(variations.len(), 2);
+            assert_eq!(variations[0].len(), 2);
+            assert_eq!(
+                variations[0][1].variations,
+                vec![vec![pgn_move("Nf6"), pgn_move("c4")]]
+            );
+            assert_eq!(variations[1], vec![pgn_move("c4")]);
+            assert_eq!(games[0].moves[1], pgn_move("e5"));
+        }
+
+        #[test]
+        fn test_multiple_games() {
+            let games: Vec<PgnGame> = parse_pgn(
+                "[White \"A\"]\n\n1. e4 1-0\n\n[White \"B\"]\n\n1. d4 0-1\n\n[White \"C\"]\n\n*\n",
+            )
+            .unwrap();
+            assert_eq!(games.len(), 3);
+            assert_eq!(games[1].get_tag("White"), Some("B"));
+            assert_eq!(games[1].moves, vec![pgn_move("d4")]);
+            assert_eq!(games[2].moves, vec![]);
+            assert_eq!(games[2].result, Some("*".to_string()));
+        }
+
+        #[test]
+        fn test_syntax_errors() {
+            assert_eq!(
+                parse_pgn("[White Alice]"),
+                Err(PgnError::InvalidTag("White Alice".to_string()))
+            );
+            assert_eq!(
+                parse_pgn("1. e4 {Unfinished"),
+                Err(PgnError::UnterminatedComment)
+            );
+            assert_eq!(
+                parse_pgn("1. e4 (1. d4 *"),
+                Err(PgnError::UnterminatedVariation)
+            );
+            assert_eq!(
+                parse_pgn("1. e4 ) *"),
+                Err(PgnError::UnexpectedToken(")".to_string()))
+            );
+            assert_eq!(
+                parse_pgn("$3 1. e4 *"),
+                Err(PgnError::UnexpectedToken("$3".to_string()))
+            );
+        }
+    }
+
+    mod test_replay {
+        use crate::{
+            game::{
+                game_status::{DrawReason, GameResult, GameStatus},
+                pgn::{parse_pgn, PgnError, PgnTags},
+                san::SanError,
+                ChessGame,
+            },
+            player::Player,
+        };
+        use std::time::Duration;
+
+        fn replay(pgn: &str) -> Result<ChessGame, PgnError> {
+            parse_pgn(pgn).unwrap()[0].replay()
+        }
+
+        #[test]
+        fn test_replays_main_line() {
+            let game: ChessGame =
+                replay("1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6?? (3... g6) 4. Qxf7# 1-0").unwrap();
+            assert_eq!(game.move_history.len(), 7);
+            assert_eq!(
+                game.status,
+                GameStatus::Finished(GameResult::Checkmate {
+                    winner: crate::player::Player::White
+                })
+            );
+        }
+
+        #[test]
+        fn test_replays_from_fen_tag() {
+            let game: ChessGame =
+                replay("[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4P3/4K3 w - - 0 1\"]\n\n1. e4 *")
+                    .unwrap();
+            assert_eq!(game.to_fen(), "4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1");
+        }
+
+        #[test]
+        fn test_comments_are_attached_to_moves() {
+            let game: ChessGame = replay("1. e4 {King's pawn} e5 *").unwrap();
+            assert_eq!(
+                game.move_history[0].comment,
+                Some("King's pawn".to_string())
+            );
+            assert_eq!(game.move_history[1].comment, None);
+        }
+
+        #[test]
+        fn test_result_token_finishes_the_game() {
+            assert_eq!(
+                replay("1. e4 e5 2. Nf3 1-0").unwrap().status,
+                GameStatus::Finished(GameResult::Recorded {
+                    winner: Some(Player::White)
+                })
+            );
+            assert_eq!(
+                replay("1. e4 e5 0-1").unwrap().status,
+                GameStatus::Finished(GameResult::Recorded {
+                    winner: Some(Player::Black)
+                })
+            );
+            assert_eq!(
+                replay("1. e4 e5 1/2-1/2").unwrap().status,
+                GameStatus::Finished(GameResult::Recorded { winner: None })
+            );
+            assert_eq!(
+                replay("1. Nf3 Nf6 2. Ng1 Ng8 3. Nf3 Nf6 4. Ng1 Ng8 1/2-1/2")
+                    .unwrap()
+                    .status,
+                GameStatus::Finished(GameResult::Draw(DrawReason::ThreefoldRepetition))
+            );
+            assert_eq!(replay("1. e4 e5 *").unwrap().status, GameStatus::Ongoing);
+        }
+
+        #[test]
+        fn test_result_token_comes_back_when_the_last_move_is_redone() {
+            let mut game: ChessGame = replay("1. e4 e5 2. Nf3 1-0").unwrap();
+            let result: GameStatus = GameStatus::Finished(GameResult::Recorded {
+                winner: Some(Player::White),
+            });
+            game.undo_move();
+            assert_eq!(game.update_status(), GameStatus::Ongoing);
+            game.undo_move();
+            game.redo_move();
+            assert_eq!(game.update_status(), GameStatus::Ongoing);
+            game.redo_move();
+            assert_eq!(game.update_status(), result);
+            assert!(game.to_pgn(&PgnTags::default()).ends_with("2. Nf3 1-0\n"));
+        }
+
+        #[test]
+        fn test_clock_commands_set_the_remaining_time() {
+            let game: ChessGame =
+                replay("1. e4 {[%clk 0:04:59]} e5 {Solid [%clk 0:04:58.5]} 2. Nf3 *").unwrap();
+            assert_eq!(
+                game.move_history[0].remaining_time,
+                Some(Duration::from_secs(299))
+            );
+            assert_eq!(game.move_history[0].comment, None);
+            assert_eq!(
+                game.move_history[1].remaining_time,
+                Some(Duration::from_millis(298_500))
+            );
+            assert_eq!(game.move_history[1].comment, Some("Solid".to_string()));
+            assert_eq!(game.move_history[2].remaining_time, None);
+        }
+
+        #[test]
+        fn test_clock_command_too_long_to_add_up_is_kept_as_a_comment() {
+            let game: ChessGame = replay("1. e4 {[%clk 18446744073709551615:00:00]} e5 *").unwrap();
+            assert_eq!(game.move_history[0].remaining_time, None);
+            assert_eq!(
+                game.move_history[0].comment,
+                Some("[%clk 18446744073709551615:00:00]".to_string())
+            );
+        }
+
+        #[test]
+        fn test_illegal_move_reports_ply_and_token() {
+            assert_eq!(
+                replay("1. e4 e5 2. Ke3 *").err(),
+                Some(PgnError::InvalidMove {
+                    ply: 3,
+                    token: "Ke3".to_string(),
+                    error: SanError::IllegalMove("Ke3".to_string()),
+                })
+            );
+        }
+
+        #[test]
+        fn test_illegal_move_in_variation_is_reported() {
+            assert_eq!(
+                replay("1. e4 e5 (1... e4) *").err(),
+                Some(PgnError::InvalidMove {
+                    ply: 2,
+                    token: "e4".to_string(),
+                    error: SanError::IllegalMove("e4".to_string()),
+                })
+            );
+        }
+
+        #[test]
+        fn test_ambiguous_move_is_reported() {
+            assert_eq!(
+                replay("[FEN \"4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1\"]\n\n1. Nd2 *").err(),
+                Some(PgnError::InvalidMove {
+                    ply: 1,
+                    token: "Nd2".to_string(),
+                    error: SanError::AmbiguousMove("Nd2".to_string()),
+                })
+            );
+        }
+
+        #[test]
+        fn test_exported_game_replays_to_the_same_position() {
+            let mut game: ChessGame =
+                replay("1. e4 d5 2. exd5 Qxd5 3. Nc3 Qa5 4. d4 c6 *").unwrap();
+            game.comment_on_last_move("Solid".to_string());
+            let replayed_game: ChessGame = parse_pgn(&game.to_pgn(&PgnTags::default())).unwrap()[0]
+                .replay()
+                .unwrap();
+            assert_eq!(replayed_game.to_fen(), game.to_fen());
+            assert_eq!(
+                replayed_game.move_history[7].comment,
+                Some("Solid".to_string())
+            );
+        }
+    }
+}
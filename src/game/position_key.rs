@@ -0,0 +1,46 @@
+use super::{board::ChessBoardData, board_position::BoardPosition, chess_move::CastlingSide};
+use crate::player::Player;
+
+/*
+    Identifies a position for the repetition rules. Two positions are the same if the same pieces
+    stand on the same positions, the same player is to move, and both players have the same
+    castling rights and en passant possibilities. How the pieces got there (such as their
+    `has_been_moved` flags) does not matter otherwise, which is why we do not compare boards directly.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PositionKey {
+    piece_letters: [[Option<char>; 8]; 8],
+    player_to_move: Player,
+    // Kingside and queenside castling rights for White, then for Black
+    castling_rights: [bool; 4],
+    // Only set when the player to move can actually capture en passant
+    en_passant_target: Option<BoardPosition>,
+}
+
+impl PositionKey {
+    pub fn new(
+        board_data: &ChessBoardData,
+        player_to_move: Player,
+        en_passant_target: Option<BoardPosition>,
+    ) -> Self {
+        let mut piece_letters: [[Option<char>; 8]; 8] = [[None; 8]; 8];
+        for (row_index, row) in board_data.board.iter().enumerate() {
+            for (column_index, piece) in row.iter().enumerate() {
+                piece_letters[row_index][column_index] =
+                    piece.as_ref().map(|piece| piece.get_piece_letter());
+            }
+        }
+
+        Self {
+            piece_letters,
+            player_to_move,
+            castling_rights: [
+                board_data.has_castling_right(&Player::White, CastlingSide::Kingside),
+                board_data.has_castling_right(&Player::White, CastlingSide::Queenside),
+                board_data.has_castling_right(&Player::Black, CastlingSide::Kingside),
+                board_data.has_castling_right(&Player::Black, CastlingSide::Queenside),
+            ],
+            en_passant_target,
+        }
+    }
+}
@@ -0,0 +1,546 @@
+use super::{
+    board_position::{BoardPosition, CandidateBoardPosition},
+    ChessGame,
+};
+use crate::{
+    piece::{ChessPiece, ChessPieceTrait},
+    player::Player,
+};
+use std::fmt::{self, Write};
+
+// ANSI escape codes for the colours of the squares and pieces
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_LIGHT_SQUARE: &str = "\x1b[48;5;223m";
+const ANSI_DARK_SQUARE: &str = "\x1b[48;5;137m";
+const ANSI_LAST_MOVE_SQUARE: &str = "\x1b[48;5;186m";
+const ANSI_LEGAL_DESTINATION_SQUARE: &str = "\x1b[48;5;108m";
+const ANSI_CHECK_SQUARE: &str = "\x1b[48;5;167m";
+const ANSI_WHITE_PIECE: &str = "\x1b[1;97m";
+const ANSI_BLACK_PIECE: &str = "\x1b[1;30m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardStyle {
+    // The bordered board with pieces drawn as "[K]" for White and "(K)" for Black
+    Classic,
+    // Piece letters, uppercase for White and lowercase for Black
+    Ascii,
+    // Chess piece glyphs, e.g. "♔"
+    Unicode,
+    // Chess piece glyphs on coloured squares, for terminals that support ANSI colours
+    Ansi,
+}
+
+impl BoardStyle {
+    pub const ALL: [BoardStyle; 4] = [
+        BoardStyle::Classic,
+        BoardStyle::Ascii,
+        BoardStyle::Unicode,
+        BoardStyle::Ansi,
+    ];
+
+    pub fn get_renderer(&self) -> &'static dyn BoardRenderer {
+        match self {
+            Self::Classic => &ClassicRenderer,
+            Self::Ascii => &AsciiRenderer,
+            Self::Unicode => &UnicodeRenderer,
+            Self::Ansi => &AnsiRenderer,
+        }
+    }
+}
+
+impl fmt::Display for BoardStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Classic => write!(f, "Classic bordered board"),
+            Self::Ascii => write!(f, "ASCII letters"),
+            Self::Unicode => write!(f, "Unicode chess pieces"),
+            Self::Ansi => write!(f, "Unicode chess pieces on coloured squares"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub style: BoardStyle,
+    // The player whose pieces are shown at the bottom of the board
+    pub perspective: Player,
+    // Whether to highlight the positions the last move was from and to
+    pub highlight_last_move: bool,
+    // A piece whose legal destinations are highlighted
+    pub selected_position: Option<BoardPosition>,
+    // Whether to highlight the king of the player to move when it is in check
+    pub highlight_check: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            style: BoardStyle::Classic,
+            perspective: Player::White,
+            highlight_last_move: true,
+            selected_position: None,
+            highlight_check: true,
+        }
+    }
+}
+
+// Why a square stands out from the others. Check takes precedence over a legal destination,
+// which takes precedence over the last move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SquareHighlight {
+    LastMove,
+    LegalDestination,
+    Check,
+}
+
+pub struct RenderedSquare<'a> {
+    pub position: BoardPosition,
+    pub piece: Option<&'a ChessPiece>,
+    pub highlight: Option<SquareHighlight>,
+}
+
+impl RenderedSquare<'_> {
+    // a1 is a dark square
+    pub fn is_light(&self) -> bool {
+        (self.position.get_row_index() + self.position.get_column_index()) % 2 == 1
+    }
+}
+
+/*
+    The squares of the board in the order they are drawn: rows from the top of the screen to
+    the bottom, and the squares of each row from left to right, as seen by the player whose
+    perspective the board is shown from.
+*/
+pub struct BoardView<'a> {
+    pub rows: Vec<Vec<RenderedSquare<'a>>>,
+    // The player whose pieces are at the bottom
+    pub perspective: Player,
+}
+
+/*
+    Draws a board as text. Every renderer is given the same view of the board, with the
+    highlights already worked out, and only decides how the squares look.
+*/
+pub trait BoardRenderer {
+    fn render(&self, view: &BoardView) -> String;
+}
+
+pub struct ClassicRenderer;
+pub struct AsciiRenderer;
+pub struct UnicodeRenderer;
+pub struct AnsiRenderer;
+
+impl BoardRenderer for ClassicRenderer {
+    fn render(&self, view: &BoardView) -> String {
+        let get_caption = |player: &Player| -> &str {
+            match player {
+                Player::White => "[White]",
+                Player::Black => "(Black)",
+            }
+        };
+        let column_labels: String = view.rows.first().map_or(String::new(), |row| {
+            row.iter()
+                .map(|square| square.position.get_column_letter().to_string())
+                .collect::<Vec<String>>()
+                .join(&" ".repeat(5))
+        });
+        let border: String = "-".repeat(49);
+
+        let mut rendered_board: String = String::new();
+        let caption_indent: String = " ".repeat(24);
+        writeln!(
+            rendered_board,
+            "{}{}\n",
+            caption_indent,
+            get_caption(&view.perspective.get_opponent())
+        )
+        .unwrap();
+        writeln!(rendered_board, "{}{}", " ".repeat(7), column_labels).unwrap();
+        writeln!(rendered_board, "    {}", border).unwrap();
+        for row in view.rows.iter() {
+            let row_label: usize = row
+                .first()
+                .map_or(0, |square| square.position.get_row_index() + 1);
+            write!(rendered_board, " {}  |", row_label).unwrap();
+            for square in row.iter() {
+                // Highlights are shown in the spaces on either side of the piece
+                let (left, right): (char, char) = match square.highlight {
+                    None => (' ', ' '),
+                    Some(SquareHighlight::LastMove) => ('>', '<'),
+                    Some(SquareHighlight::LegalDestination) => ('*', '*'),
+                    Some(SquareHighlight::Check) => ('!', '!'),
+                };
+                let symbol: &str = square.piece.map_or("   ", |piece| piece.get_board_symbol());
+                write!(rendered_board, "{}{}{}|", left, symbol, right).unwrap();
+            }
+            writeln!(rendered_board, "  {}", row_label).unwrap();
+            writeln!(rendered_board, "    {}", border).unwrap();
+        }
+        writeln!(rendered_board, "{}{}\n", " ".repeat(7), column_labels).unwrap();
+        writeln!(
+            rendered_board,
+            "{}{}",
+            caption_indent,
+            get_caption(&view.perspective)
+        )
+        .unwrap();
+        rendered_board
+    }
+}
+
+impl BoardRenderer for AsciiRenderer {
+    fn render(&self, view: &BoardView) -> String {
+        render_grid(view, |square| {
+            let symbol: char = square.piece.map_or('.', |piece| piece.get_piece_letter());
+            mark_square(symbol, square.highlight)
+        })
+    }
+}
+
+impl BoardRenderer for UnicodeRenderer {
+    fn render(&self, view: &BoardView) -> String {
+        render_grid(view, |square| {
+            let symbol: char = square.piece.map_or('·', |piece| {
+                get_piece_glyph(piece, piece.get_player() == &Player::Black)
+            });
+            mark_square(symbol, square.highlight)
+        })
+    }
+}
+
+impl BoardRenderer for AnsiRenderer {
+    fn render(&self, view: &BoardView) -> String {
+        render_grid(view, |square| {
+            let background: &str = match square.highlight {
+                Some(SquareHighlight::Check) => ANSI_CHECK_SQUARE,
+                Some(SquareHighlight::LegalDestination) => ANSI_LEGAL_DESTINATION_SQUARE,
+                Some(SquareHighlight::LastMove) => ANSI_LAST_MOVE_SQUARE,
+                None if square.is_light() => ANSI_LIGHT_SQUARE,
+                None => ANSI_DARK_SQUARE,
+            };
+            match square.piece {
+                // The filled glyphs are the easiest to read, with the colour telling them apart
+                Some(piece) => {
+                    let foreground: &str = match piece.get_player() {
+                        Player::White => ANSI_WHITE_PIECE,
+                        Player::Black => ANSI_BLACK_PIECE,
+                    };
+                    format!(
+                        "{}{} {} {}",
+                        background,
+                        foreground,
+                        get_piece_glyph(piece, true),
+                        ANSI_RESET
+                    )
+                }
+                None => format!("{}   {}", background, ANSI_RESET),
+            }
+        })
+    }
+}
+
+impl ChessGame {
+    // Draws the board in the given style, with the highlights the options ask for
+    pub fn render_board(&self, options: &RenderOptions) -> String {
+        options
+            .style
+            .get_renderer()
+            .render(&self.get_board_view(options))
+    }
+
+    pub fn get_board_view(&self, options: &RenderOptions) -> BoardView<'_> {
+        let board = &self.board_data.board;
+        let player: Player = self.get_current_player();
+
+        let last_move_positions: Vec<BoardPosition> = match self.move_history.last() {
+            Some(history_entry) if options.highlight_last_move => {
+                vec![history_entry.chess_move.from, history_entry.chess_move.to]
+            }
+            _ => vec![],
+        };
+        let legal_destinations: Vec<BoardPosition> = match &options.selected_position {
+            Some(selected_position) => self
+                .legal_move_list(&player)
+                .iter()
+                .filter(|legal_move| legal_move.from == *selected_position)
+                .map(|legal_move| legal_move.to)
+                .collect(),
+            None => vec![],
+        };
+        let king_in_check: Option<BoardPosition> =
+            if options.highlight_check && self.board_data.is_in_check(&player) {
+                self.board_data.get_king_position(&player).copied()
+            } else {
+                None
+            };
+
+        let board_size: usize = board.len();
+        let (row_indices, column_indices): (Vec<usize>, Vec<usize>) = match options.perspective {
+            Player::White => ((0..board_size).rev().collect(), (0..board_size).collect()),
+            Player::Black => ((0..board_size).collect(), (0..board_size).rev().collect()),
+        };
+        let rows: Vec<Vec<RenderedSquare>> = row_indices
+            .iter()
+            .map(|row_index| {
+                column_indices
+                    .iter()
+                    .map(|column_index| {
+                        let position: BoardPosition = CandidateBoardPosition {
+                            row_index: *row_index as i32,
+                            column_index: *column_index as i32,
+                        }
+                        .validate_candidate_position_and_unwrap(board);
+                        let highlight: Option<SquareHighlight> = if king_in_check == Some(position)
+                        {
+                            Some(SquareHighlight::Check)
+                        } else if legal_destinations.contains(&position) {
+                            Some(SquareHighlight::LegalDestination)
+                        } else if last_move_positions.contains(&position) {
+                            Some(SquareHighlight::LastMove)
+                        } else {
+                            None
+                        };
+                        RenderedSquare {
+                            position,
+                            piece: board[*row_index][*column_index].as_ref(),
+                            highlight,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        BoardView {
+            rows,
+            perspective: options.perspective,
+        }
+    }
+}
+
+/*
+    Lays the squares out in a grid with the rows labelled on both sides and the columns above
+    and below. Each square is drawn three characters wide by `render_square`.
+*/
+fn render_grid(view: &BoardView, render_square: impl Fn(&RenderedSquare) -> String) -> String {
+    let column_labels: String = view.rows.first().map_or(String::new(), |row| {
+        row.iter()
+            .map(|square| format!(" {} ", square.position.get_column_letter()))
+            .collect()
+    });
+
+    let mut rendered_board: String = String::new();
+    writeln!(rendered_board, "  {}", column_labels.trim_end()).unwrap();
+    for row in view.rows.iter() {
+        let row_label: usize = row
+            .first()
+            .map_or(0, |square| square.position.get_row_index() + 1);
+        let squares: String = row.iter().map(&render_square).collect();
+        writeln!(rendered_board, "{} {} {}", row_label, squares, row_label).unwrap();
+    }
+    writeln!(rendered_board, "  {}", column_labels.trim_end()).unwrap();
+    rendered_board
+}
+
+// Surrounds the symbol on a square with characters that show how it is highlighted
+fn mark_square(symbol: char, highlight: Option<SquareHighlight>) -> String {
+    let (left, right): (char, char) = match highlight {
+        None => (' ', ' '),
+        Some(SquareHighlight::LastMove) => ('[', ']'),
+        Some(SquareHighlight::LegalDestination) => ('<', '>'),
+        Some(SquareHighlight::Check) => ('!', '!'),
+    };
+    format!("{}{}{}", left, symbol, right)
+}
+
+// The outlined glyphs are traditionally White's pieces and the filled glyphs Black's
+pub fn get_piece_glyph(piece: &ChessPiece, filled: bool) -> char {
+    match (piece, filled) {
+        (ChessPiece::King(_), false) => '♔',
+        (ChessPiece::Queen(_), false) => '♕',
+        (ChessPiece::Rook(_), false) => '♖',
+        (ChessPiece::Bishop(_), false) => '♗',
+        (ChessPiece::Knight(_), false) => '♘',
+        (ChessPiece::Pawn(_), false) => '♙',
+        (ChessPiece::King(_), true) => '♚',
+        (ChessPiece::Queen(_), true) => '♛',
+        (ChessPiece::Rook(_), true) => '♜',
+        (ChessPiece::Bishop(_), true) => '♝',
+        (ChessPiece::Knight(_), true) => '♞',
+        (ChessPiece::Pawn(_), true) => '♟',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_render_board {
+        use crate::{
+            game::{
+                board_position::get_position,
+                render::{BoardStyle, RenderOptions},
+                ChessGame,
+            },
+            player::Player,
+        };
+
+        fn ascii_options() -> RenderOptions {
+            RenderOptions {
+                style: BoardStyle::Ascii,
+                ..RenderOptions::default()
+            }
+        }
+
+        #[test]
+        fn test_classic_starting_position() {
+            let game: ChessGame = ChessGame::new();
+            let border: &str = "    -------------------------------------------------\n";
+            let mut expected_board: String = String::new();
+            expected_board.push_str("                        (Black)\n\n");
+            expected_board.push_str("       a     b     c     d     e     f     g     h\n");
+            expected_board.push_str(border);
+            for row in [
+                " 8  | (R) | (N) | (B) | (Q) | (K) | (B) | (N) | (R) |  8\n",
+                " 7  | (P) | (P) | (P) | (P) | (P) | (P) | (P) | (P) |  7\n",
+                " 6  |     |     |     |     |     |     |     |     |  6\n",
+                " 5  |     |     |     |     |     |     |     |     |  5\n",
+                " 4  |     |     |     |     |     |     |     |     |  4\n",
+                " 3  |     |     |     |     |     |     |     |     |  3\n",
+                " 2  | [P] | [P] | [P] | [P] | [P] | [P] | [P] | [P] |  2\n",
+                " 1  | [R] | [N] | [B] | [Q] | [K] | [B] | [N] | [R] |  1\n",
+            ] {
+                expected_board.push_str(row);
+                expected_board.push_str(border);
+            }
+            expected_board.push_str("       a     b     c     d     e     f     g     h\n\n");
+            expected_board.push_str("                        [White]\n");
+            assert_eq!(game.render_board(&RenderOptions::default()), expected_board);
+        }
+
+        #[test]
+        fn test_classic_highlights() {
+            let mut game: ChessGame = ChessGame::new();
+            game.make_move(&get_position("e2"), &get_position("e4"))
+                .unwrap();
+            let rendered_board: String = game.render_board(&RenderOptions {
+                perspective: Player::Black,
+                selected_position: Some(get_position("g8")),
+                ..RenderOptions::default()
+            });
+            let lines: Vec<&str> = rendered_board.lines().collect();
+            assert_eq!(lines[0], "                        [White]");
+            assert_eq!(
+                lines[10],
+                " 4  |     |     |     |>[P]<|     |     |     |     |  4"
+            );
+            assert_eq!(
+                lines[16],
+                " 7  | (P) | (P) | (P) | (P) | (P) | (P) | (P) | (P) |  7"
+            );
+            assert_eq!(
+                lines[14],
+                " 6  |*   *|     |*   *|     |     |     |     |     |  6"
+            );
+        }
+
+        #[test]
+        fn test_ascii_starting_position() {
+            let game: ChessGame = ChessGame::new();
+            assert_eq!(
+                game.render_board(&ascii_options()),
+                concat!(
+                    "   a  b  c  d  e  f  g  h\n",
+                    "8  r  n  b  q  k  b  n  r  8\n",
+                    "7  p  p  p  p  p  p  p  p  7\n",
+                    "6  .  .  .  .  .  .  .  .  6\n",
+                    "5  .  .  .  .  .  .  .  .  5\n",
+                    "4  .  .  .  .  .  .  .  .  4\n",
+                    "3  .  .  .  .  .  .  .  .  3\n",
+                    "2  P  P  P  P  P  P  P  P  2\n",
+                    "1  R  N  B  Q  K  B  N  R  1\n",
+                    "   a  b  c  d  e  f  g  h\n",
+                )
+            );
+        }
+
+        #[test]
+        fn test_black_perspective() {
+            let game: ChessGame = ChessGame::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+            let rendered_board: String = game.render_board(&RenderOptions {
+                perspective: Player::Black,
+                ..ascii_options()
+            });
+            let lines: Vec<&str> = rendered_board.lines().collect();
+            assert_eq!(lines[0], "   h  g  f  e  d  c  b  a");
+            assert_eq!(lines[1], "1  .  .  .  K  .  .  .  R  1");
+            assert_eq!(lines[8], "8  .  .  .  k  .  .  .  .  8");
+        }
+
+        #[test]
+        fn test_highlights_last_move() {
+            let mut game: ChessGame = ChessGame::new();
+            game.make_move(&get_position("e2"), &get_position("e4"))
+                .unwrap();
+            let rendered_board: String = game.render_board(&ascii_options());
+            let lines: Vec<&str> = rendered_board.lines().collect();
+            assert_eq!(lines[5], "4  .  .  .  . [P] .  .  .  4");
+            assert_eq!(lines[7], "2  P  P  P  P [.] P  P  P  2");
+
+            let rendered_board: String = game.render_board(&RenderOptions {
+                highlight_last_move: false,
+                ..ascii_options()
+            });
+            assert!(!rendered_board.contains('['));
+        }
+
+        #[test]
+        fn test_highlights_legal_destinations() {
+            let game: ChessGame = ChessGame::from_fen("4k3/8/8/8/8/2p5/8/1N2K3 w - - 0 1").unwrap();
+            let rendered_board: String = game.render_board(&RenderOptions {
+                selected_position: Some(get_position("b1")),
+                ..ascii_options()
+            });
+            let lines: Vec<&str> = rendered_board.lines().collect();
+            assert_eq!(lines[6], "3 <.> . <p> .  .  .  .  .  3");
+            assert_eq!(lines[7], "2  .  .  . <.> .  .  .  .  2");
+        }
+
+        #[test]
+        fn test_highlights_king_in_check() {
+            let game: ChessGame = ChessGame::from_fen("4k3/8/8/8/8/8/8/4RK2 b - - 0 1").unwrap();
+            let rendered_board: String = game.render_board(&ascii_options());
+            assert!(rendered_board.contains("!k!"));
+
+            let rendered_board: String = game.render_board(&RenderOptions {
+                highlight_check: false,
+                ..ascii_options()
+            });
+            assert!(!rendered_board.contains('!'));
+        }
+
+        #[test]
+        fn test_unicode_glyphs() {
+            let game: ChessGame = ChessGame::new();
+            let rendered_board: String = game.render_board(&RenderOptions {
+                style: BoardStyle::Unicode,
+                ..ascii_options()
+            });
+            let lines: Vec<&str> = rendered_board.lines().collect();
+            assert_eq!(lines[1], "8  ♜  ♞  ♝  ♛  ♚  ♝  ♞  ♜  8");
+            assert_eq!(lines[3], "6  ·  ·  ·  ·  ·  ·  ·  ·  6");
+            assert_eq!(lines[8], "1  ♖  ♘  ♗  ♕  ♔  ♗  ♘  ♖  1");
+        }
+
+        #[test]
+        fn test_ansi_colours() {
+            let game: ChessGame = ChessGame::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+            let rendered_board: String = game.render_board(&RenderOptions {
+                style: BoardStyle::Ansi,
+                ..ascii_options()
+            });
+            let lines: Vec<&str> = rendered_board.lines().collect();
+            // a1 is dark and b1 is light
+            assert!(lines[8].starts_with("1 \x1b[48;5;137m   \x1b[0m\x1b[48;5;223m   \x1b[0m"));
+            assert!(lines[8].contains("\x1b[1;97m ♚ \x1b[0m"));
+            assert!(lines[1].contains("\x1b[1;30m ♚ \x1b[0m"));
+        }
+    }
+}
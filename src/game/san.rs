@@ -0,0 +1,709 @@
+use super::{
+    board_position::{BoardPosition, CandidateBoardPosition},
+    chess_move::{CastlingSide, LegalMove, Move, MoveError, PromotionPiece},
+    ChessGame,
+};
+use crate::piece::{ChessPiece, ChessPieceTrait};
+use std::{fmt, mem};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SanError {
+    InvalidNotation(String),
+    // No legal move matches the notation
+    IllegalMove(String),
+    // More than one legal move matches the notation
+    AmbiguousMove(String),
+}
+
+impl fmt::Display for SanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNotation(san) => {
+                write!(f, "\"{}\" is not a move in algebraic notation", san)
+            }
+            Self::IllegalMove(san) => write!(f, "\"{}\" is not a legal move", san),
+            Self::AmbiguousMove(san) => {
+                write!(f, "\"{}\" could be more than one legal move", san)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SanError {}
+
+// The parts of a (non-castling) move in SAN, e.g. "Nbxd7" or "exd8=Q"
+struct SanPattern {
+    // Uppercase, with 'P' for pawn moves, which leave the letter out
+    piece_letter: char,
+    from_column_index: Option<usize>,
+    from_row_index: Option<usize>,
+    to: BoardPosition,
+    promotion: Option<PromotionPiece>,
+}
+
+impl SanPattern {
+    fn parse(notation: &str, game: &ChessGame) -> Option<SanPattern> {
+        let (piece_letter, notation): (char, &str) = match notation.chars().next()? {
+            letter @ ('N' | 'B' | 'R' | 'Q' | 'K') => (letter, &notation[1..]),
+            _ => ('P', notation),
+        };
+        // The "=" before the promotion piece is often left out, as in "e8Q"
+        let promotion: Option<PromotionPiece> = PromotionPiece::ALL
+            .into_iter()
+            .find(|promotion| notation.ends_with(promotion.get_piece_letter()));
+        let notation: &str = match promotion {
+            Some(_) => {
+                let notation: &str = &notation[..notation.len() - 1];
+                notation.strip_suffix('=').unwrap_or(notation)
+            }
+            None => notation,
+        };
+
+        let to_index: usize = notation.len().checked_sub(2)?;
+        let to: BoardPosition =
+            CandidateBoardPosition::from_algebraic_notation(notation.get(to_index..)?)?
+                .validate_candidate_position(&game.board_data.board)?;
+        let disambiguation: &str = notation[..to_index]
+            .strip_suffix('x')
+            .unwrap_or(&notation[..to_index]);
+
+        let mut from_column_index: Option<usize> = None;
+        let mut from_row_index: Option<usize> = None;
+        for letter in disambiguation.chars() {
+            match letter {
+                'a'..='h' if from_column_index.is_none() && from_row_index.is_none() => {
+                    from_column_index = Some(letter as usize - 'a' as usize)
+                }
+                '1'..='8' if from_row_index.is_none() => {
+                    from_row_index = Some(letter as usize - '1' as usize)
+                }
+                _ => return None,
+            }
+        }
+
+        Some(SanPattern {
+            piece_letter,
+            from_column_index,
+            from_row_index,
+            to,
+            promotion,
+        })
+    }
+
+    fn matches(&self, legal_move: &LegalMove, game: &ChessGame) -> bool {
+        let from: &BoardPosition = &legal_move.from;
+        legal_move.to == self.to
+            && legal_move.promotion == self.promotion
+            && self
+                .from_column_index
+                .is_none_or(|column_index| column_index == from.get_column_index())
+            && self
+                .from_row_index
+                .is_none_or(|row_index| row_index == from.get_row_index())
+            && game.board_data.board[from.get_row_index()][from.get_column_index()]
+                .as_ref()
+                .is_some_and(|piece| {
+                    piece.get_piece_letter().to_ascii_uppercase() == self.piece_letter
+                })
+    }
+}
+
+impl ChessGame {
+    /*
+        Finds the legal move for the player to move that `san` describes in Standard Algebraic
+        Notation, e.g. "Nbd7", "exd6", "e8=Q" or "O-O". Check and checkmate suffixes, as well as
+        annotations such as "!?", are accepted but not required, and so are the common
+        variations "e8Q" and "0-0".
+    */
+    pub fn parse_san(&self, san: &str) -> Result<LegalMove, SanError> {
+        let player = self.get_current_player();
+        let notation: &str = san.trim_end_matches(['+', '#', '!', '?']);
+        let legal_move_list: Vec<LegalMove> = self.legal_move_list(&player);
+
+        // Castling is sometimes written with zeros, as in "0-0"
+        let notation: String = match notation {
+            "0-0" | "0-0-0" => notation.replace('0', "O"),
+            _ => notation.to_string(),
+        };
+
+        let matching_moves: Vec<LegalMove> = match notation.as_str() {
+            "O-O" | "O-O-O" => legal_move_list
+                .into_iter()
+                .filter(|legal_move| {
+                    let is_king: bool = matches!(
+                        self.board_data.board[legal_move.from.get_row_index()]
+                            [legal_move.from.get_column_index()],
+                        Some(ChessPiece::King(_))
+                    );
+                    let column_index: usize = legal_move.from.get_column_index();
+                    is_king
+                        && legal_move.to.get_column_index()
+                            == if notation == "O-O" {
+                                column_index + 2
+                            } else {
+                                column_index.wrapping_sub(2)
+                            }
+                })
+                .collect(),
+            _ => {
+                let pattern: SanPattern = SanPattern::parse(&notation, self)
+                    .ok_or(SanError::InvalidNotation(san.to_string()))?;
+                legal_move_list
+                    .into_iter()
+                    .filter(|legal_move| pattern.matches(legal_move, self))
+                    .collect()
+            }
+        };
+
+        match matching_moves.as_slice() {
+            [legal_move] => Ok(*legal_move),
+            [] => Err(SanError::IllegalMove(san.to_string())),
+            _ => Err(SanError::AmbiguousMove(san.to_string())),
+        }
+    }
+}
+
+impl ChessGame {
+    /*
+        Finds the legal move for the player to move that `input` describes, either in long
+        algebraic notation ("g1f3", "Ng1-f3", "e7e8q"), in coordinates ("g1 f3") or in SAN.
+    */
+    pub fn parse_move(&self, input: &str) -> Result<LegalMove, SanError> {
+        let input: &str = input.trim();
+        let Some((from, to, promotion)) = self.parse_coordinates(input) else {
+            return self.parse_san(input);
+        };
+
+        // A promoting move given without a promotion piece matches all four promotions
+        let matching_moves: Vec<LegalMove> = self
+            .legal_move_list(&self.get_current_player())
+            .into_iter()
+            .filter(|legal_move| {
+                legal_move.from == from
+                    && legal_move.to == to
+                    && promotion.is_none_or(|promotion| legal_move.promotion == Some(promotion))
+            })
+            .collect();
+        match matching_moves.as_slice() {
+            [legal_move] => Ok(*legal_move),
+            [] => Err(SanError::IllegalMove(input.to_string())),
+            _ => Err(SanError::AmbiguousMove(input.to_string())),
+        }
+    }
+
+    /*
+        Writes every move made so far in SAN. Each move is written against the position it was
+        made in, which its history entry keeps a copy of, so nothing has to be replayed.
+    */
+    pub fn get_san_move_list(&self) -> Vec<String> {
+        self.move_history
+            .iter()
+            .map(|history_entry| {
+                // Only the parts of the game that decide which moves are legal are needed
+                let game_before_move: ChessGame = ChessGame {
+                    board_data: history_entry.board_data.clone(),
+                    turn: history_entry.turn,
+                    status: history_entry.status,
+                    move_history: vec![],
+                    undone_moves: vec![],
+                    en_passant_target: history_entry.en_passant_target,
+                    halfmove_clock: history_entry.halfmove_clock,
+                    position_history: vec![],
+                    starting_fen: String::new(),
+                    clock: None,
+                };
+                history_entry.chess_move.to_san(&game_before_move)
+            })
+            .collect()
+    }
+
+    /*
+        Writes a legal move for the player to move in SAN, without making the move. The move is
+        tried out on a copy of the game without its clock, so a clock that has run out does not
+        stop it from being written.
+    */
+    pub fn get_legal_move_san(&self, legal_move: &LegalMove) -> Result<String, MoveError> {
+        let mut game: ChessGame = self.clone();
+        game.clock = None;
+        let chess_move: Move =
+            game.make_move_with_promotion(&legal_move.from, &legal_move.to, legal_move.promotion)?;
+        Ok(chess_move.to_san(self))
+    }
+
+    /*
+        Reads the positions a move is from and to, and its promotion piece, when they are all
+        given, e.g. "g1f3", "Ng1-f3", "g1 f3", "e5xd6" or "e7e8=Q". The piece letter in front is
+        optional, but must match the piece that is moved if it is there.
+    */
+    fn parse_coordinates(
+        &self,
+        input: &str,
+    ) -> Option<(BoardPosition, BoardPosition, Option<PromotionPiece>)> {
+        let board = &self.board_data.board;
+        let notation: &str = input.trim_end_matches(['+', '#', '!', '?']);
+        let (piece_letter, notation): (Option<char>, &str) = match notation.chars().next()? {
+            letter @ ('N' | 'B' | 'R' | 'Q' | 'K') => (Some(letter), &notation[1..]),
+            _ => (None, notation),
+        };
+
+        let from: BoardPosition =
+            CandidateBoardPosition::from_algebraic_notation(notation.get(..2)?)?
+                .validate_candidate_position(board)?;
+        let notation: &str = notation[2..]
+            .strip_prefix(['-', 'x', ' '])
+            .unwrap_or(&notation[2..]);
+        let to: BoardPosition =
+            CandidateBoardPosition::from_algebraic_notation(notation.get(..2)?)?
+                .validate_candidate_position(board)?;
+        let promotion_letter: &str = notation[2..].strip_prefix('=').unwrap_or(&notation[2..]);
+        let promotion: Option<PromotionPiece> =
+            match promotion_letter.chars().next() {
+                None => None,
+                Some(_) if promotion_letter.len() > 1 => return None,
+                Some(letter) => Some(PromotionPiece::ALL.into_iter().find(|promotion| {
+                    promotion.get_piece_letter() == letter.to_ascii_uppercase()
+                })?),
+            };
+
+        let piece_matches: bool = piece_letter.is_none_or(|piece_letter| {
+            board[from.get_row_index()][from.get_column_index()]
+                .as_ref()
+                .is_some_and(|piece| piece.get_piece_letter().to_ascii_uppercase() == piece_letter)
+        });
+        piece_matches.then_some((from, to, promotion))
+    }
+}
+
+impl LegalMove {
+    // Writes the move in long algebraic notation as used by chess engines, e.g. "e7e8q"
+    pub fn to_long_algebraic(self) -> String {
+        let mut notation: String = format!("{}{}", self.from, self.to);
+        if let Some(promotion) = &self.promotion {
+            notation.push(promotion.get_piece_letter().to_ascii_lowercase());
+        }
+        notation
+    }
+}
+
+impl Move {
+    /*
+        Writes the move in Standard Algebraic Notation (SAN), e.g. "Nbd7", "exd6", "e8=Q+" or
+        "O-O-O#". `game` must be the game as it was just before the move was made, since that is
+        where we look for other pieces that could have moved to the same position.
+
+        When another piece of the same kind could also have moved there, the move is
+        disambiguated by the column the piece moved from, then by the row if the column is
+        shared, and by both if neither is enough on its own.
+    */
+    pub fn to_san(&self, game: &ChessGame) -> String {
+        let mut san: String = match self.flags.castling {
+            Some(CastlingSide::Kingside) => "O-O".to_string(),
+            Some(CastlingSide::Queenside) => "O-O-O".to_string(),
+            None => {
+                let mut san: String = String::new();
+                match &self.piece {
+                    ChessPiece::Pawn(_) => {
+                        if self.captured_piece.is_some() {
+                            san.push(self.from.get_column_letter());
+                        }
+                    }
+                    piece => {
+                        san.push(piece.get_piece_letter().to_ascii_uppercase());
+                        san.push_str(&self.get_disambiguation(game));
+                    }
+                }
+                if self.captured_piece.is_some() {
+                    san.push('x');
+                }
+                san.push_str(&self.to.to_string());
+                if let Some(promotion) = &self.flags.promotion {
+                    san.push('=');
+                    san.push(promotion.get_piece_letter());
+                }
+                san
+            }
+        };
+
+        if self.flags.is_checkmate {
+            san.push('#');
+        } else if self.flags.is_check {
+            san.push('+');
+        }
+        san
+    }
+
+    fn get_disambiguation(&self, game: &ChessGame) -> String {
+        let player_pieces = game.board_data.get_player_pieces(self.piece.get_player());
+        let other_origins: Vec<BoardPosition> = game
+            .legal_moves(self.piece.get_player())
+            .iter()
+            .filter(|(from, piece_move_data)| {
+                **from != self.from
+                    && player_pieces.get(from).is_some_and(|piece| {
+                        mem::discriminant(piece) == mem::discriminant(&self.piece)
+                    })
+                    && (piece_move_data.valid_moves.contains(&self.to)
+                        || piece_move_data.valid_captures.contains(&self.to))
+            })
+            .map(|(from, _)| *from)
+            .collect();
+
+        if other_origins.is_empty() {
+            String::new()
+        } else if other_origins
+            .iter()
+            .all(|from| from.get_column_index() != self.from.get_column_index())
+        {
+            self.from.get_column_letter().to_string()
+        } else if other_origins
+            .iter()
+            .all(|from| from.get_row_index() != self.from.get_row_index())
+        {
+            (self.from.get_row_index() + 1).to_string()
+        } else {
+            self.from.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_to_san {
+        use crate::game::{
+            board_position::get_position,
+            chess_move::{Move, PromotionPiece},
+            ChessGame,
+        };
+
+        // Plays the move on `game` and returns its SAN
+        fn play(
+            game: &mut ChessGame,
+            from: &str,
+            to: &str,
+            promotion: Option<PromotionPiece>,
+        ) -> String {
+            let game_before_move: ChessGame = ChessGame::from_fen(&game.to_fen()).unwrap();
+            let chess_move: Move = game
+                .make_move_with_promotion(&get_position(from), &get_position(to), promotion)
+                .unwrap();
+            chess_move.to_san(&game_before_move)
+        }
+
+        #[test]
+        fn test_pawn_and_piece_moves() {
+            let mut game: ChessGame = ChessGame::new();
+            assert_eq!(play(&mut game, "e2", "e4", None), "e4");
+            assert_eq!(play(&mut game, "d7", "d5", None), "d5");
+            assert_eq!(play(&mut game, "e4", "d5", None), "exd5");
+            assert_eq!(play(&mut game, "d8", "d5", None), "Qxd5");
+            assert_eq!(play(&mut game, "g1", "f3", None), "Nf3");
+        }
+
+        #[test]
+        fn test_castling() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+            assert_eq!(play(&mut game, "e1", "g1", None), "O-O");
+            assert_eq!(play(&mut game, "e8", "c8", None), "O-O-O");
+        }
+
+        #[test]
+        fn test_disambiguation_by_column() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1").unwrap();
+            assert_eq!(play(&mut game, "b1", "d2", None), "Nbd2");
+        }
+
+        #[test]
+        fn test_disambiguation_by_row() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1").unwrap();
+            assert_eq!(play(&mut game, "a1", "a3", None), "R1a3");
+        }
+
+        #[test]
+        fn test_disambiguation_by_column_and_row() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("1k6/8/8/8/4Q2Q/8/8/K6Q w - - 0 1").unwrap();
+            assert_eq!(play(&mut game, "h4", "e1", None), "Qh4e1");
+        }
+
+        #[test]
+        fn test_pinned_piece_does_not_need_disambiguation() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("4k3/8/8/8/8/8/8/r1N1KN2 w - - 0 1").unwrap();
+            // The knight on c1 is pinned against the king, so only the knight on f1 can reach d2
+            assert_eq!(play(&mut game, "f1", "d2", None), "Nd2");
+        }
+
+        #[test]
+        fn test_promotion_with_check() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("1r5k/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+            assert_eq!(
+                play(&mut game, "a7", "b8", Some(PromotionPiece::Queen)),
+                "axb8=Q+"
+            );
+        }
+
+        #[test]
+        fn test_en_passant_capture() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2").unwrap();
+            assert_eq!(play(&mut game, "e5", "d6", None), "exd6");
+        }
+
+        #[test]
+        fn test_checkmate() {
+            let mut game: ChessGame = ChessGame::new();
+            play(&mut game, "f2", "f3", None);
+            play(&mut game, "e7", "e5", None);
+            play(&mut game, "g2", "g4", None);
+            assert_eq!(play(&mut game, "d8", "h4", None), "Qh4#");
+        }
+    }
+
+    mod test_parse_san {
+        use crate::game::{
+            board_position::get_position,
+            chess_move::{LegalMove, PromotionPiece},
+            san::SanError,
+            ChessGame,
+        };
+
+        fn legal_move(from: &str, to: &str, promotion: Option<PromotionPiece>) -> LegalMove {
+            LegalMove {
+                from: get_position(from),
+                to: get_position(to),
+                promotion,
+            }
+        }
+
+        #[test]
+        fn test_pawn_and_piece_moves() {
+            let game: ChessGame = ChessGame::new();
+            assert_eq!(game.parse_san("e4"), Ok(legal_move("e2", "e4", None)));
+            assert_eq!(game.parse_san("Nf3!"), Ok(legal_move("g1", "f3", None)));
+        }
+
+        #[test]
+        fn test_disambiguation() {
+            let game: ChessGame = ChessGame::from_fen("1k6/8/8/8/4Q2Q/8/8/K6Q w - - 0 1").unwrap();
+            assert_eq!(game.parse_san("Qh4e1"), Ok(legal_move("h4", "e1", None)));
+            assert_eq!(
+                game.parse_san("Qe1"),
+                Err(SanError::AmbiguousMove("Qe1".to_string()))
+            );
+            assert_eq!(
+                game.parse_san("Qhe1"),
+                Err(SanError::AmbiguousMove("Qhe1".to_string()))
+            );
+            assert_eq!(game.parse_san("Qee1"), Ok(legal_move("e4", "e1", None)));
+        }
+
+        #[test]
+        fn test_castling_and_promotion() {
+            let game: ChessGame =
+                ChessGame::from_fen("r3k2r/1P6/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+            assert_eq!(game.parse_san("O-O"), Ok(legal_move("e1", "g1", None)));
+            assert_eq!(game.parse_san("O-O-O"), Ok(legal_move("e1", "c1", None)));
+            assert_eq!(
+                game.parse_san("bxa8=N"),
+                Ok(legal_move("b7", "a8", Some(PromotionPiece::Knight)))
+            );
+            assert_eq!(
+                game.parse_san("b8=Q+"),
+                Ok(legal_move("b7", "b8", Some(PromotionPiece::Queen)))
+            );
+        }
+
+        #[test]
+        fn test_lenient_notation() {
+            let game: ChessGame =
+                ChessGame::from_fen("r3k2r/1P6/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+            assert_eq!(game.parse_san("0-0"), Ok(legal_move("e1", "g1", None)));
+            assert_eq!(game.parse_san("0-0-0"), Ok(legal_move("e1", "c1", None)));
+            assert_eq!(
+                game.parse_san("b8R"),
+                Ok(legal_move("b7", "b8", Some(PromotionPiece::Rook)))
+            );
+        }
+
+        #[test]
+        fn test_every_legal_move_round_trips_through_san() {
+            let positions: [&str; 4] = [
+                "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+                "r3k2r/1P4P1/8/3pP3/8/2N1N3/8/R3K2R w KQkq d6 0 1",
+                "1k6/8/8/8/4Q2Q/8/8/K6Q w - - 0 1",
+                "4k3/8/8/8/8/8/8/r1N1KN2 w - - 0 1",
+            ];
+            for fen in positions {
+                let game: ChessGame = ChessGame::from_fen(fen).unwrap();
+                for expected_move in game.legal_move_list(&game.get_current_player()) {
+                    let san: String = game
+                        .clone()
+                        .make_move_with_promotion(
+                            &expected_move.from,
+                            &expected_move.to,
+                            expected_move.promotion,
+                        )
+                        .unwrap()
+                        .to_san(&game);
+                    assert_eq!(
+                        game.parse_san(&san),
+                        Ok(expected_move),
+                        "{} in {}",
+                        san,
+                        fen
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_invalid_and_illegal_moves() {
+            let game: ChessGame = ChessGame::new();
+            assert_eq!(
+                game.parse_san("Zz9"),
+                Err(SanError::InvalidNotation("Zz9".to_string()))
+            );
+            assert_eq!(
+                game.parse_san("e5"),
+                Err(SanError::IllegalMove("e5".to_string()))
+            );
+            assert_eq!(
+                game.parse_san("O-O"),
+                Err(SanError::IllegalMove("O-O".to_string()))
+            );
+        }
+    }
+
+    mod test_parse_move {
+        use crate::{
+            game::{
+                board_position::get_position,
+                chess_move::{LegalMove, MoveError, PromotionPiece},
+                san::SanError,
+                ChessGame,
+            },
+            player::Player,
+        };
+        use std::time::{Duration, Instant};
+
+        fn legal_move(from: &str, to: &str, promotion: Option<PromotionPiece>) -> LegalMove {
+            LegalMove {
+                from: get_position(from),
+                to: get_position(to),
+                promotion,
+            }
+        }
+
+        #[test]
+        fn test_long_algebraic_and_coordinates() {
+            let game: ChessGame = ChessGame::new();
+            let knight_move: LegalMove = legal_move("g1", "f3", None);
+            for input in ["g1f3", "Ng1-f3", "g1 f3", "g1-f3", " Nf3 "] {
+                assert_eq!(game.parse_move(input), Ok(knight_move), "{}", input);
+            }
+        }
+
+        #[test]
+        fn test_piece_letter_must_match() {
+            let game: ChessGame = ChessGame::new();
+            assert_eq!(
+                game.parse_move("Bg1f3"),
+                Err(SanError::IllegalMove("Bg1f3".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_promotion() {
+            let game: ChessGame = ChessGame::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+            assert_eq!(
+                game.parse_move("b7b8n"),
+                Ok(legal_move("b7", "b8", Some(PromotionPiece::Knight)))
+            );
+            assert_eq!(
+                game.parse_move("b7-b8=Q"),
+                Ok(legal_move("b7", "b8", Some(PromotionPiece::Queen)))
+            );
+            assert_eq!(
+                game.parse_move("b7b8"),
+                Err(SanError::AmbiguousMove("b7b8".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_illegal_move() {
+            let game: ChessGame = ChessGame::new();
+            assert_eq!(
+                game.parse_move("e2e5"),
+                Err(SanError::IllegalMove("e2e5".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_legal_move_notation() {
+            let game: ChessGame = ChessGame::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+            let promotion: LegalMove = legal_move("b7", "b8", Some(PromotionPiece::Queen));
+            assert_eq!(game.get_legal_move_san(&promotion), Ok("b8=Q+".to_string()));
+            assert_eq!(promotion.to_long_algebraic(), "b7b8q");
+            // Writing the move in SAN does not make it
+            assert!(game.move_history.is_empty());
+        }
+
+        #[test]
+        fn test_legal_move_notation_after_running_out_of_time() {
+            let mut game: ChessGame = ChessGame::new();
+            game.start_clock("1".parse().unwrap());
+            let started_at: Instant = Instant::now().checked_sub(Duration::from_secs(61)).unwrap();
+            game.clock
+                .as_mut()
+                .unwrap()
+                .start(Player::White, started_at);
+            let knight_move: LegalMove = legal_move("g1", "f3", None);
+            assert_eq!(game.get_legal_move_san(&knight_move), Ok("Nf3".to_string()));
+            assert!(!game.status.is_finished());
+        }
+
+        #[test]
+        fn test_legal_move_notation_in_a_finished_game() {
+            let mut game: ChessGame = ChessGame::new();
+            let knight_move: LegalMove = legal_move("g1", "f3", None);
+            game.resign(&Player::White);
+            assert_eq!(
+                game.get_legal_move_san(&knight_move),
+                Err(MoveError::GameIsFinished)
+            );
+        }
+    }
+
+    mod test_get_san_move_list {
+        use crate::game::ChessGame;
+
+        #[test]
+        fn test_moves_are_written_against_their_own_positions() {
+            let mut game: ChessGame =
+                ChessGame::from_fen("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1").unwrap();
+            for san in ["Nbd2", "Kd7", "Nf3"] {
+                let legal_move = game.parse_san(san).unwrap();
+                game.make_move(&legal_move.from, &legal_move.to).unwrap();
+            }
+            // Only the first move needed disambiguating, as both knights could reach d2
+            assert_eq!(game.get_san_move_list(), vec!["Nbd2", "Kd7", "Nf3"]);
+        }
+
+        #[test]
+        fn test_game_set_up_from_a_board_that_fen_rejects() {
+            // Black is in check with White to move, which FEN import does not allow
+            let board = ChessGame::from_fen("4k3/8/8/8/8/8/8/4R1K1 b - - 0 1")
+                .unwrap()
+                .board_data
+                .board;
+            let mut game: ChessGame = ChessGame::from_board(board, 0);
+            let legal_move = game.parse_san("Kh1").unwrap();
+            game.make_move(&legal_move.from, &legal_move.to).unwrap();
+            assert_eq!(game.get_san_move_list(), vec!["Kh1+"]);
+        }
+    }
+}
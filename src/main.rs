@@ -1,55 +1,688 @@
 mod game;
 mod piece;
 mod player;
+mod tui;
 
-use game::ChessGame;
-use inquire::Select;
-use piece::{ChessPiece, ChessPieceTrait, PieceMoveData};
+use game::{
+    board_position::BoardPosition,
+    chess_move::LegalMove,
+    clock::{format_duration, TimeControl},
+    fen::STARTING_POSITION_FEN,
+    game_status::{DrawReason, GameStatus},
+    pgn::{parse_pgn, PgnGame, PgnTags},
+    render::{BoardStyle, RenderOptions},
+    ChessGame,
+};
+use inquire::{Confirm, CustomUserError, Select, Text};
+use piece::{ChessPiece, ChessPieceTrait};
 use player::Player;
+use std::{fmt, fs, time::Instant};
+
+#[derive(Debug, Clone, Copy)]
+enum TurnAction {
+    MovePiece,
+    UndoMove,
+    RedoMove,
+    CommentOnLastMove,
+    OfferDraw,
+    ClaimDraw(DrawReason),
+    Resign,
+    ShowFen,
+    SaveGame,
+    SwitchMoveEntry(MoveEntryMode),
+    ChangeBoardPerspective,
+    ChangeBoardStyle,
+    OpenFullScreenView,
+    Quit,
+}
+
+// How the player to move chooses their move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MoveEntryMode {
+    // Picking the piece and then its destination from lists
+    Select,
+    // Typing the move in algebraic notation
+    Type,
+}
+
+// Whose side the board is shown from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoardPerspective {
+    // Turns the board around after every move, so the player to move is always at the bottom
+    PlayerToMove,
+    Fixed(Player),
+}
+
+impl BoardPerspective {
+    const ALL: [BoardPerspective; 3] = [
+        BoardPerspective::PlayerToMove,
+        BoardPerspective::Fixed(Player::White),
+        BoardPerspective::Fixed(Player::Black),
+    ];
+
+    fn get_player(&self, game: &ChessGame) -> Player {
+        match self {
+            BoardPerspective::PlayerToMove => game.get_current_player(),
+            BoardPerspective::Fixed(player) => *player,
+        }
+    }
+}
+
+impl fmt::Display for BoardPerspective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardPerspective::PlayerToMove => write!(f, "From the side of the player to move"),
+            BoardPerspective::Fixed(player) => write!(f, "Always from Player {}'s side", player),
+        }
+    }
+}
+
+// How the board is shown between turns
+#[derive(Debug, Clone, Copy)]
+struct BoardDisplay {
+    perspective: BoardPerspective,
+    style: BoardStyle,
+}
+
+impl BoardDisplay {
+    // Shows the board, highlighting where the piece on `selected_position` can move to
+    fn show(&self, game: &ChessGame, selected_position: Option<BoardPosition>) {
+        println!(
+            "\n{}",
+            game.render_board(&RenderOptions {
+                style: self.style,
+                perspective: self.perspective.get_player(game),
+                selected_position,
+                ..RenderOptions::default()
+            })
+        );
+    }
+}
+
+impl TurnAction {
+    fn get_available_actions(game: &ChessGame, move_entry_mode: MoveEntryMode) -> Vec<TurnAction> {
+        // A finished game that can still be stepped through only lets moves be taken back
+        if game.status.is_finished() {
+            return vec![
+                TurnAction::UndoMove,
+                TurnAction::ShowFen,
+                TurnAction::SaveGame,
+                TurnAction::ChangeBoardPerspective,
+                TurnAction::ChangeBoardStyle,
+                TurnAction::Quit,
+            ];
+        }
+
+        let mut actions: Vec<TurnAction> = vec![TurnAction::MovePiece];
+        // Moves can not be taken back against the clock
+        if !game.move_history.is_empty() && game.clock.is_none() {
+            actions.push(TurnAction::UndoMove);
+        }
+        if !game.undone_moves.is_empty() && game.clock.is_none() {
+            actions.push(TurnAction::RedoMove);
+        }
+        if !game.move_history.is_empty() {
+            actions.push(TurnAction::CommentOnLastMove);
+        }
+        actions.push(TurnAction::OfferDraw);
+        if let GameStatus::DrawClaimable(reason) = game.status {
+            actions.push(TurnAction::ClaimDraw(reason));
+        }
+        actions.push(TurnAction::Resign);
+        actions.push(TurnAction::ShowFen);
+        actions.push(TurnAction::SaveGame);
+        actions.push(TurnAction::SwitchMoveEntry(match move_entry_mode {
+            MoveEntryMode::Select => MoveEntryMode::Type,
+            MoveEntryMode::Type => MoveEntryMode::Select,
+        }));
+        actions.push(TurnAction::ChangeBoardPerspective);
+        actions.push(TurnAction::ChangeBoardStyle);
+        actions.push(TurnAction::OpenFullScreenView);
+        actions
+    }
+}
+
+impl fmt::Display for TurnAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TurnAction::MovePiece => write!(f, "Move a piece"),
+            TurnAction::UndoMove => write!(f, "Undo the last move"),
+            TurnAction::RedoMove => write!(f, "Redo the last undone move"),
+            TurnAction::CommentOnLastMove => write!(f, "Comment on the last move"),
+            TurnAction::OfferDraw => write!(f, "Offer a draw"),
+            TurnAction::ClaimDraw(reason) => write!(f, "Claim a draw by {}", reason),
+            TurnAction::Resign => write!(f, "Resign"),
+            TurnAction::ShowFen => write!(f, "Show the FEN of the current position"),
+            TurnAction::SaveGame => write!(f, "Save the game to a PGN file"),
+            TurnAction::SwitchMoveEntry(MoveEntryMode::Select) => {
+                write!(f, "Switch to selecting moves from lists")
+            }
+            TurnAction::SwitchMoveEntry(MoveEntryMode::Type) => write!(f, "Switch to typing moves"),
+            TurnAction::ChangeBoardPerspective => {
+                write!(f, "Change whose side the board is shown from")
+            }
+            TurnAction::ChangeBoardStyle => write!(f, "Change how the board looks"),
+            TurnAction::OpenFullScreenView => write!(f, "Play in the full-screen view"),
+            TurnAction::Quit => write!(f, "Quit"),
+        }
+    }
+}
+
+/*
+    Asks whether to play from the starting position, from a position given in FEN or on from a
+    game in a PGN file. A new game can be played against the clock, but a loaded one is not, so
+    that its moves can be stepped through. Returns the game and whether it was loaded.
+*/
+fn set_up_game() -> (ChessGame, bool) {
+    const STARTING_POSITION: &str = "Start from the starting position";
+    const FEN_POSITION: &str = "Set up a position from FEN";
+    const PGN_GAME: &str = "Load a game from a PGN file";
+    let choice: &str = Select::new(
+        "How would you like to start the game?",
+        vec![STARTING_POSITION, FEN_POSITION, PGN_GAME],
+    )
+    .prompt()
+    .unwrap_or_else(|err| {
+        panic!(
+            "Failed to select a starting position due to an Inquire error: {:?}",
+            err
+        )
+    });
+    let mut game: ChessGame = match choice {
+        STARTING_POSITION => ChessGame::new(),
+        FEN_POSITION => loop {
+            let fen: String = Text::new("Please enter the FEN of the position:")
+                .with_default(STARTING_POSITION_FEN)
+                .prompt()
+                .unwrap_or_else(|err| {
+                    panic!("Failed to read a FEN due to an Inquire error: {:?}", err)
+                });
+            match ChessGame::from_fen(&fen) {
+                Ok(game) => break game,
+                Err(err) => println!("{}", err),
+            }
+        },
+        _ => loop {
+            if let Some(game) = load_game() {
+                println!("Loaded the game. Undo and redo moves to step through it.");
+                return (game, true);
+            }
+        },
+    };
+    if let Some(time_control) = choose_time_control() {
+        game.start_clock(time_control);
+    }
+    (game, false)
+}
+
+// Asks for the time control to play with, if the game is to be played against the clock
+fn choose_time_control() -> Option<TimeControl> {
+    loop {
+        let input: String =
+            Text::new("Please enter a time control, or leave it empty to play without clocks:")
+                .with_help_message(
+                    "Minutes, then seconds per move: 5+3 (increment), 15d10 (delay) \
+                     or 40/90+30, 30+30 (periods)",
+                )
+                .prompt()
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to read a time control due to an Inquire error: {:?}",
+                        err
+                    )
+                });
+        if input.trim().is_empty() {
+            return None;
+        }
+        match input.parse() {
+            Ok(time_control) => return Some(time_control),
+            Err(err) => println!("{}", err),
+        }
+    }
+}
+
+// Shows the time left on each player's clock, in a timed game
+fn show_clocks(game: &ChessGame) {
+    if let Some(clock) = &game.clock {
+        let now: Instant = Instant::now();
+        println!(
+            "Clocks ({}): Player White {} | Player Black {}",
+            clock.time_control,
+            format_duration(clock.get_remaining_time(&Player::White, now)),
+            format_duration(clock.get_remaining_time(&Player::Black, now))
+        );
+    }
+}
+
+// Asks for a PGN file and which game in it to load, then replays that game
+fn load_game() -> Option<ChessGame> {
+    let file_name: String = Text::new("Please enter the PGN file to load:")
+        .with_default("game.pgn")
+        .prompt()
+        .unwrap_or_else(|err| {
+            panic!(
+                "Failed to read a file name due to an Inquire error: {:?}",
+                err
+            )
+        });
+    let pgn: String = fs::read_to_string(&file_name)
+        .map_err(|err| println!("Failed to read {}: {}", file_name, err))
+        .ok()?;
+    let mut games: Vec<PgnGame> = parse_pgn(&pgn)
+        .map_err(|err| println!("Failed to read {}: {}", file_name, err))
+        .ok()?;
+
+    let game_index: usize = match games.len() {
+        0 => {
+            println!("There are no games in {}", file_name);
+            return None;
+        }
+        1 => 0,
+        _ => {
+            let labels: Vec<String> = games
+                .iter()
+                .map(|pgn_game| {
+                    format!(
+                        "{} vs {} ({})",
+                        pgn_game.get_tag("White").unwrap_or("?"),
+                        pgn_game.get_tag("Black").unwrap_or("?"),
+                        pgn_game.result.as_deref().unwrap_or("*")
+                    )
+                })
+                .collect();
+            Select::new("Which game would you like to load?", labels)
+                .raw_prompt()
+                .unwrap_or_else(|err| {
+                    panic!("Failed to select a game due to an Inquire error: {:?}", err)
+                })
+                .index
+        }
+    };
+    games
+        .swap_remove(game_index)
+        .replay()
+        .map_err(|err| println!("Failed to load the game: {}", err))
+        .ok()
+}
+
+/*
+    Lets the player to move choose a move, the same way for either player, and makes it. The
+    pieces to choose from come from that player's half of the board data.
+*/
+fn play_turn(
+    game: &mut ChessGame,
+    player: &Player,
+    move_entry_mode: MoveEntryMode,
+    board_display: BoardDisplay,
+) {
+    // The time may have run out while the player was choosing what to do
+    if game.check_flag_fall().is_finished() {
+        return;
+    }
+    let LegalMove {
+        from,
+        to,
+        promotion,
+    } = match move_entry_mode {
+        MoveEntryMode::Select => select_move(game, player, board_display),
+        MoveEntryMode::Type => type_move(game),
+    };
+    // Writing the move in SAN needs the game as it was before the move
+    let game_before_move: ChessGame = game.clone();
+    let move_result = match promotion {
+        Some(_) => game.make_move_with_promotion(&from, &to, promotion),
+        None => game.make_move(&from, &to),
+    };
+    match move_result {
+        Ok(chess_move) => {
+            println!("{} ({})", chess_move, chess_move.to_san(&game_before_move));
+            board_display.show(game, None);
+        }
+        Err(err) => println!("{}", err),
+    }
+}
+
+// A choice in the list of where the selected piece can move to
+enum DestinationChoice {
+    Move {
+        legal_move: LegalMove,
+        label: String,
+    },
+    Back,
+}
+
+impl DestinationChoice {
+    /*
+        Describes a legal move by its destination in algebraic notation, marking captures and
+        castling. A promoting move is listed once per piece the pawn can be promoted to.
+    */
+    fn new(game: &ChessGame, legal_move: LegalMove) -> DestinationChoice {
+        let LegalMove {
+            from,
+            to,
+            promotion,
+        } = legal_move;
+        let board = &game.board_data.board;
+        let piece: &ChessPiece = board[from.get_row_index()][from.get_column_index()]
+            .as_ref()
+            .unwrap_or_else(|| panic!("There is no piece on {}", from));
+
+        let mut notes: Vec<String> = vec![];
+        if let Some(captured_piece) = &board[to.get_row_index()][to.get_column_index()] {
+            notes.push(format!("captures {}", captured_piece.get_piece_name()));
+        } else if matches!(piece, ChessPiece::Pawn(_))
+            && from.get_column_index() != to.get_column_index()
+        {
+            notes.push("captures Pawn en passant".to_string());
+        }
+        if let Some(promotion) = promotion {
+            notes.push(format!("promotes to {}", promotion));
+        }
+        if matches!(piece, ChessPiece::King(_)) {
+            match to.get_column_index() as i32 - from.get_column_index() as i32 {
+                2 => notes.push("castles kingside".to_string()),
+                -2 => notes.push("castles queenside".to_string()),
+                _ => {}
+            }
+        }
+
+        let label: String = if notes.is_empty() {
+            to.to_string()
+        } else {
+            format!("{} ({})", to, notes.join(", "))
+        };
+        DestinationChoice::Move { legal_move, label }
+    }
+}
+
+impl fmt::Display for DestinationChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DestinationChoice::Move { label, .. } => write!(f, "{}", label),
+            DestinationChoice::Back => write!(f, "Back to choosing a piece"),
+        }
+    }
+}
+
+// Sorts positions from a1 to h8, row by row, so that the lists we show are in a stable order
+fn get_position_sort_key(position: &BoardPosition) -> (usize, usize) {
+    (position.get_row_index(), position.get_column_index())
+}
+
+/*
+    Asks for a piece to move, then where to move it, with each promotion listed as its own
+    destination. Going back from the list of destinations asks for the piece again.
+*/
+fn select_move(game: &ChessGame, player: &Player, board_display: BoardDisplay) -> LegalMove {
+    let legal_move_list: Vec<LegalMove> = game.legal_move_list(player);
+    let player_pieces = game.board_data.get_player_pieces(player);
+    let mut available_pieces: Vec<&ChessPiece> = game
+        .legal_moves(player)
+        .keys()
+        .filter_map(|position| player_pieces.get(position))
+        .collect();
+    available_pieces.sort_by_key(|piece| get_position_sort_key(piece.get_board_position()));
+
+    loop {
+        let piece: &ChessPiece =
+            Select::new("Please select a piece to move:", available_pieces.clone())
+                .prompt()
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to select a piece due to an Inquire error: {:?}",
+                        err
+                    )
+                });
+
+        let mut piece_moves: Vec<LegalMove> = legal_move_list
+            .iter()
+            .filter(|legal_move| legal_move.from == *piece.get_board_position())
+            .copied()
+            .collect();
+        // Promotions to the same position keep the order of PromotionPiece::ALL
+        piece_moves.sort_by_key(|legal_move| get_position_sort_key(&legal_move.to));
+        let mut destinations: Vec<DestinationChoice> = piece_moves
+            .into_iter()
+            .map(|legal_move| DestinationChoice::new(game, legal_move))
+            .collect();
+        destinations.push(DestinationChoice::Back);
+
+        board_display.show(game, Some(*piece.get_board_position()));
+        let destination: DestinationChoice =
+            Select::new("Please select where to move the piece:", destinations)
+                .prompt()
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to select a destination due to an Inquire error: {:?}",
+                        err
+                    )
+                });
+        if let DestinationChoice::Move { legal_move, .. } = destination {
+            return legal_move;
+        }
+    }
+}
+
+/*
+    Asks for a move to be typed in SAN ("Nf3"), long algebraic notation ("g1f3") or coordinates
+    ("g1 f3"), suggesting the legal moves that start with what has been typed so far. Asks again
+    until the input is a legal move.
+*/
+fn type_move(game: &ChessGame) -> LegalMove {
+    let mut move_notations: Vec<(String, String)> = game
+        .legal_move_list(&game.get_current_player())
+        .iter()
+        .filter_map(|legal_move| {
+            let san: String = game.get_legal_move_san(legal_move).ok()?;
+            Some((san, legal_move.to_long_algebraic()))
+        })
+        .collect();
+    move_notations.sort();
+    let suggest_moves = move |input: &str| -> Result<Vec<String>, CustomUserError> {
+        let input: &str = input.trim();
+        Ok(move_notations
+            .iter()
+            .filter_map(|(san, long_algebraic)| {
+                if san.starts_with(input) {
+                    Some(san.clone())
+                } else if long_algebraic.starts_with(input) {
+                    Some(long_algebraic.clone())
+                } else {
+                    None
+                }
+            })
+            .collect())
+    };
+
+    loop {
+        let input: String = Text::new("Please enter your move:")
+            .with_help_message("e.g. Nf3, g1f3 or g1 f3. Press Tab to complete a suggestion")
+            .with_autocomplete(suggest_moves.clone())
+            .prompt()
+            .unwrap_or_else(|err| {
+                panic!("Failed to read a move due to an Inquire error: {:?}", err)
+            });
+        match game.parse_move(&input) {
+            Ok(legal_move) => return legal_move,
+            Err(err) => println!("{}", err),
+        }
+    }
+}
+
+// Asks for the players' names and a file name, and writes the game there in PGN
+fn save_game(game: &ChessGame) {
+    fn prompt_text(message: &str, default: &str) -> String {
+        Text::new(message)
+            .with_default(default)
+            .prompt()
+            .unwrap_or_else(|err| panic!("Failed to read text due to an Inquire error: {:?}", err))
+    }
+
+    let default_tags: PgnTags = PgnTags::default();
+    let tags: PgnTags = PgnTags {
+        white: prompt_text("Who played as White?", &default_tags.white),
+        black: prompt_text("Who played as Black?", &default_tags.black),
+        ..default_tags
+    };
+    let file_name: String = prompt_text("Please enter a file name to save to:", "game.pgn");
+    match fs::write(&file_name, game.to_pgn(&tags)) {
+        Ok(()) => println!("Saved the game to {}", file_name),
+        Err(err) => println!("Failed to save the game to {}: {}", file_name, err),
+    }
+}
 
 fn main() {
     // Initialize ChessGame
-    let mut game = ChessGame::new();
-    game.display_board();
+    let (mut game, loaded): (ChessGame, bool) = set_up_game();
+    let mut move_entry_mode: MoveEntryMode = MoveEntryMode::Select;
+    let mut board_display: BoardDisplay = BoardDisplay {
+        perspective: BoardPerspective::PlayerToMove,
+        style: BoardStyle::Classic,
+    };
+    board_display.show(&game, None);
 
     loop {
-        let players_turn: Player = if game.turn % 2 == 0 {
-            Player::White
+        game.check_flag_fall();
+        let players_turn: Player = game.get_current_player();
+        if let GameStatus::Finished(result) = game.update_status() {
+            show_clocks(&game);
+            println!("{}", result);
+            // A loaded game stays open once it has finished, so its moves can be stepped through
+            if !loaded || game.move_history.is_empty() {
+                break;
+            }
         } else {
-            Player::Black
-        };
-        println!("Turn {}: Player {}'s Turn", game.turn + 1, players_turn);
-        match players_turn {
-            Player::White => {
-                let available_pieces: Vec<&ChessPiece> =
-                    game.board_data.white_pieces.values().collect();
-
-                let piece: &ChessPiece =
-                    Select::new("Please select a piece to move:", available_pieces)
-                        .prompt()
-                        .unwrap_or_else(|err| {
-                            panic!(
-                                "Failed to select a piece due to an Inquire error: {:?}",
-                                err
-                            )
-                        });
-
-                let piece_move_data = piece.valid_moves_and_captures(&game.board_data.board);
-                let PieceMoveData {
-                    valid_moves,
-                    valid_captures,
-                } = piece_move_data;
-                println!("{:?}", valid_moves);
-                println!("{:?}", valid_captures);
-            }
-            Player::Black => {
-                println!("TODO");
-            }
-        }
-
-        game.turn += 1;
-        if game.turn == 3 {
-            break;
+            println!("Turn {}: Player {}'s Turn", game.turn + 1, players_turn);
+            show_clocks(&game);
+            if game.board_data.is_in_check(&players_turn) {
+                println!("Player {} is in check!", players_turn);
+            }
+            if let GameStatus::DrawClaimable(reason) = game.status {
+                println!("Player {} may claim a draw by {}", players_turn, reason);
+            }
         }
+
+        let action: TurnAction = Select::new(
+            "What would you like to do?",
+            TurnAction::get_available_actions(&game, move_entry_mode),
+        )
+        .prompt()
+        .unwrap_or_else(|err| {
+            panic!(
+                "Failed to select an action due to an Inquire error: {:?}",
+                err
+            )
+        });
+        match action {
+            TurnAction::MovePiece => {
+                play_turn(&mut game, &players_turn, move_entry_mode, board_display)
+            }
+            TurnAction::SwitchMoveEntry(mode) => {
+                move_entry_mode = mode;
+            }
+            TurnAction::ChangeBoardPerspective => {
+                board_display.perspective = Select::new(
+                    "Whose side would you like to see the board from?",
+                    BoardPerspective::ALL.to_vec(),
+                )
+                .prompt()
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to select a perspective due to an Inquire error: {:?}",
+                        err
+                    )
+                });
+                board_display.show(&game, None);
+            }
+            TurnAction::ChangeBoardStyle => {
+                board_display.style = Select::new(
+                    "How would you like the board to look?",
+                    BoardStyle::ALL.to_vec(),
+                )
+                .prompt()
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to select a board style due to an Inquire error: {:?}",
+                        err
+                    )
+                });
+                board_display.show(&game, None);
+            }
+            TurnAction::OpenFullScreenView => {
+                if let Err(err) = tui::run(&mut game) {
+                    println!("Failed to run the full-screen view: {}", err);
+                }
+                board_display.show(&game, None);
+            }
+            TurnAction::UndoMove => {
+                if let Some(chess_move) = game.undo_move() {
+                    println!("Undid: {}", chess_move);
+                    board_display.show(&game, None);
+                }
+            }
+            TurnAction::RedoMove => {
+                if let Some(chess_move) = game.redo_move() {
+                    println!("Redid: {}", chess_move);
+                    board_display.show(&game, None);
+                }
+            }
+            TurnAction::OfferDraw => {
+                let accepted: bool = Confirm::new(&format!(
+                    "Player {} offers a draw. Does Player {} accept?",
+                    players_turn,
+                    players_turn.get_opponent()
+                ))
+                .with_default(false)
+                .prompt()
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to answer the draw offer due to an Inquire error: {:?}",
+                        err
+                    )
+                });
+                if accepted {
+                    game.agree_draw();
+                } else {
+                    println!("The draw offer was declined.");
+                }
+            }
+            TurnAction::ClaimDraw(_) => {
+                game.claim_draw();
+            }
+            TurnAction::Resign => {
+                game.resign(&players_turn);
+            }
+            TurnAction::CommentOnLastMove => {
+                let comment: String = Text::new("Please enter your comment:")
+                    .prompt()
+                    .unwrap_or_else(|err| {
+                        panic!(
+                            "Failed to read a comment due to an Inquire error: {:?}",
+                            err
+                        )
+                    });
+                game.comment_on_last_move(comment);
+            }
+            TurnAction::ShowFen => {
+                println!("{}", game.to_fen());
+            }
+            TurnAction::SaveGame => {
+                save_game(&game);
+            }
+            TurnAction::Quit => break,
+        }
+    }
+
+    let save: bool = Confirm::new("Would you like to save the game to a PGN file?")
+        .with_default(false)
+        .prompt()
+        .unwrap_or_else(|err| {
+            panic!(
+                "Failed to answer whether to save the game due to an Inquire error: {:?}",
+                err
+            )
+        });
+    if save {
+        save_game(&game);
     }
 }
@@ -6,7 +6,7 @@ pub mod queen;
 pub mod rook;
 
 use crate::game::board::ChessBoard;
-use crate::game::board_position::BoardPosition;
+use crate::game::board_position::{BoardPosition, CandidateBoardPosition};
 use crate::player::Player;
 use bishop::Bishop;
 use king::King;
@@ -22,12 +22,78 @@ pub struct PieceMoveData {
     pub valid_captures: Vec<BoardPosition>,
 }
 
+// (row_offset, column_offset) pairs for the four orthogonal directions a Rook or Queen slides in
+pub const ORTHOGONAL_DIRECTIONS: [(i32, i32); 4] = [
+    (1, 0),  // upward
+    (-1, 0), // downward
+    (0, -1), // left
+    (0, 1),  // right
+];
+
+// (row_offset, column_offset) pairs for the four diagonal directions a Bishop or Queen slides in
+pub const DIAGONAL_DIRECTIONS: [(i32, i32); 4] = [
+    (1, -1),  // upward-left diagonal
+    (1, 1),   // upward-right diagonal
+    (-1, -1), // downward-left diagonal
+    (-1, 1),  // downward-right diagonal
+];
+
+/*
+    Bishops, Rooks and Queens are all "sliding" pieces: they move any number of positions in a
+    straight line until they reach the edge of the board or another piece. They only differ in
+    the directions they are allowed to slide in, so all three share this ray-walking loop.
+
+    A sliding piece cannot jump over a piece, so the first piece in each direction stops the
+    movement possibilities in that direction. If that piece belongs to the opposing player, it
+    is reported as a capture.
+*/
+pub fn sliding_moves_and_captures(
+    player: &Player,
+    position: &BoardPosition,
+    directions: &[(i32, i32)],
+    board: &ChessBoard,
+) -> PieceMoveData {
+    let mut valid_moves: Vec<BoardPosition> = vec![];
+    let mut valid_captures: Vec<BoardPosition> = vec![];
+
+    for (row_offset, column_offset) in directions.iter() {
+        let mut distance: i32 = 1;
+        loop {
+            let candidate_position: CandidateBoardPosition = CandidateBoardPosition {
+                row_index: position.get_row_index() as i32 + *row_offset * distance,
+                column_index: position.get_column_index() as i32 + *column_offset * distance,
+            };
+
+            match candidate_position.validate_candidate_position(board) {
+                Some(candidate_position) if candidate_position.is_move_valid(board) => {
+                    valid_moves.push(candidate_position);
+                    distance += 1;
+                }
+                Some(candidate_position) => {
+                    if candidate_position.is_capture_valid(player, board) {
+                        valid_captures.push(candidate_position);
+                    }
+                    break;
+                }
+                None => break,
+            }
+        }
+    }
+
+    PieceMoveData {
+        valid_moves,
+        valid_captures,
+    }
+}
+
 pub trait ChessPieceTrait {
     fn get_player(&self) -> &Player;
     fn valid_moves_and_captures(&self, board: &ChessBoard) -> PieceMoveData;
-    fn display_piece_on_board(&self);
+    // How the piece is drawn on the classic board, e.g. "[K]" for White's king
+    fn get_board_symbol(&self) -> &str;
     fn get_piece_name(&self) -> &str;
     fn get_board_position(&self) -> &BoardPosition;
+    fn set_board_position(&mut self, position: BoardPosition);
     // Default trait method
     fn get_position_str(&self) -> String {
         format!(
@@ -39,7 +105,7 @@ pub trait ChessPieceTrait {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ChessPiece {
     Pawn(Pawn),
     Bishop(Bishop),
@@ -49,6 +115,34 @@ pub enum ChessPiece {
     King(King),
 }
 
+impl ChessPiece {
+    // Pawns, Kings and Rooks track whether they have been moved, as it affects how they can move next
+    pub fn mark_as_moved(&mut self) {
+        match self {
+            ChessPiece::Pawn(pawn) => pawn.has_been_moved = true,
+            ChessPiece::King(king) => king.has_been_moved = true,
+            ChessPiece::Rook(rook) => rook.has_been_moved = true,
+            ChessPiece::Bishop(_) | ChessPiece::Knight(_) | ChessPiece::Queen(_) => {}
+        }
+    }
+
+    // The letter used for the piece in chess notation (uppercase for White, lowercase for Black)
+    pub fn get_piece_letter(&self) -> char {
+        let letter: char = match self {
+            ChessPiece::Pawn(_) => 'P',
+            ChessPiece::Bishop(_) => 'B',
+            ChessPiece::Knight(_) => 'N',
+            ChessPiece::Rook(_) => 'R',
+            ChessPiece::Queen(_) => 'Q',
+            ChessPiece::King(_) => 'K',
+        };
+        match self.get_player() {
+            Player::White => letter,
+            Player::Black => letter.to_ascii_lowercase(),
+        }
+    }
+}
+
 impl fmt::Display for ChessPiece {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.get_position_str())
@@ -78,14 +172,14 @@ impl ChessPieceTrait for ChessPiece {
         }
     }
 
-    fn display_piece_on_board(&self) {
+    fn get_board_symbol(&self) -> &str {
         match self {
-            ChessPiece::Pawn(pawn) => pawn.display_piece_on_board(),
-            ChessPiece::Bishop(bishop) => bishop.display_piece_on_board(),
-            ChessPiece::Knight(knight) => knight.display_piece_on_board(),
-            ChessPiece::Rook(rook) => rook.display_piece_on_board(),
-            ChessPiece::Queen(queen) => queen.display_piece_on_board(),
-            ChessPiece::King(king) => king.display_piece_on_board(),
+            ChessPiece::Pawn(pawn) => pawn.get_board_symbol(),
+            ChessPiece::Bishop(bishop) => bishop.get_board_symbol(),
+            ChessPiece::Knight(knight) => knight.get_board_symbol(),
+            ChessPiece::Rook(rook) => rook.get_board_symbol(),
+            ChessPiece::Queen(queen) => queen.get_board_symbol(),
+            ChessPiece::King(king) => king.get_board_symbol(),
         }
     }
 
@@ -110,4 +204,15 @@ impl ChessPieceTrait for ChessPiece {
             ChessPiece::King(king) => king.get_board_position(),
         }
     }
+
+    fn set_board_position(&mut self, position: BoardPosition) {
+        match self {
+            ChessPiece::Pawn(pawn) => pawn.set_board_position(position),
+            ChessPiece::Bishop(bishop) => bishop.set_board_position(position),
+            ChessPiece::Knight(knight) => knight.set_board_position(position),
+            ChessPiece::Rook(rook) => rook.set_board_position(position),
+            ChessPiece::Queen(queen) => queen.set_board_position(position),
+            ChessPiece::King(king) => king.set_board_position(position),
+        }
+    }
 }
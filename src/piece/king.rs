@@ -1,13 +1,21 @@
 use crate::{
-    game::board::ChessBoard,
-    piece::{BoardPosition, ChessPieceTrait, PieceMoveData},
+    game::{
+        board::{is_square_attacked, ChessBoard},
+        board_position::CandidateBoardPosition,
+    },
+    piece::{
+        BoardPosition, ChessPiece, ChessPieceTrait, PieceMoveData, DIAGONAL_DIRECTIONS,
+        ORTHOGONAL_DIRECTIONS,
+    },
     player::Player,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct King {
     pub player: Player,
     pub position: BoardPosition,
+    // A King that has been moved can no longer castle
+    pub has_been_moved: bool,
 }
 
 impl ChessPieceTrait for King {
@@ -16,13 +24,47 @@ impl ChessPieceTrait for King {
     }
 
     fn valid_moves_and_captures(&self, board: &ChessBoard) -> PieceMoveData {
-        todo!();
+        /*
+            Both Black and White Kings can move a single position in any of the eight
+            orthogonal and diagonal directions, capturing an opposing piece found there.
+
+            Castling moves are added to the valid moves as the position the king lands on.
+            Moving the castled rook alongside the king is handled when the move is made.
+        */
+
+        let mut valid_moves: Vec<BoardPosition> = vec![];
+        let mut valid_captures: Vec<BoardPosition> = vec![];
+
+        for (row_offset, column_offset) in ORTHOGONAL_DIRECTIONS
+            .iter()
+            .chain(DIAGONAL_DIRECTIONS.iter())
+        {
+            let position: CandidateBoardPosition = CandidateBoardPosition {
+                row_index: self.position.get_row_index() as i32 + *row_offset,
+                column_index: self.position.get_column_index() as i32 + *column_offset,
+            };
+
+            if let Some(position) = position.validate_candidate_position(board) {
+                if position.is_move_valid(board) {
+                    valid_moves.push(position);
+                } else if position.is_capture_valid(&self.player, board) {
+                    valid_captures.push(position);
+                }
+            }
+        }
+
+        valid_moves.extend(self.valid_castling_moves(board));
+
+        PieceMoveData {
+            valid_moves,
+            valid_captures,
+        }
     }
 
-    fn display_piece_on_board(&self) {
+    fn get_board_symbol(&self) -> &str {
         match self.player {
-            Player::Black => print!("(K)"),
-            Player::White => print!("[K]"),
+            Player::Black => "(K)",
+            Player::White => "[K]",
         }
     }
 
@@ -33,4 +75,915 @@ impl ChessPieceTrait for King {
     fn get_board_position(&self) -> &BoardPosition {
         &self.position
     }
+
+    fn set_board_position(&mut self, position: BoardPosition) {
+        self.position = position;
+    }
+}
+
+impl King {
+    fn get_home_row_index(&self) -> usize {
+        match self.player {
+            Player::White => 0,
+            Player::Black => 7,
+        }
+    }
+
+    fn valid_castling_moves(&self, board: &ChessBoard) -> Vec<BoardPosition> {
+        /*
+            A King may castle with a Rook when:
+              - neither the king nor that rook have been moved,
+              - every position between the king and the rook is empty, and
+              - the king is not in check, and does not move through or onto an attacked position.
+
+            Kingside castling moves the king two positions right (towards column 7), and
+            queenside castling moves the king two positions left (towards column 0).
+        */
+
+        let mut valid_moves: Vec<BoardPosition> = vec![];
+        let row_index: usize = self.get_home_row_index();
+        let opponent: Player = self.player.get_opponent();
+
+        if self.has_been_moved
+            || self.position.get_row_index() != row_index
+            || self.position.get_column_index() != 4
+            || is_square_attacked(board, &self.position, &opponent)
+        {
+            return valid_moves;
+        }
+
+        // (rook column, columns that must be empty, columns the king passes through or lands on)
+        let castling_sides: [(usize, &[usize], [usize; 2]); 2] = [
+            (7, &[5, 6], [5, 6]),    // kingside
+            (0, &[1, 2, 3], [3, 2]), // queenside
+        ];
+
+        for (rook_column_index, empty_column_indices, king_path_column_indices) in castling_sides {
+            let rook_can_castle: bool = matches!(
+                &board[row_index][rook_column_index],
+                Some(ChessPiece::Rook(rook)) if rook.player == self.player && !rook.has_been_moved
+            );
+            let path_is_empty: bool = empty_column_indices
+                .iter()
+                .all(|column_index| board[row_index][*column_index].is_none());
+            let path_is_safe: bool = king_path_column_indices.iter().all(|column_index| {
+                let position: BoardPosition = CandidateBoardPosition {
+                    row_index: row_index as i32,
+                    column_index: *column_index as i32,
+                }
+                .validate_candidate_position_and_unwrap(board);
+                !is_square_attacked(board, &position, &opponent)
+            });
+
+            if rook_can_castle && path_is_empty && path_is_safe {
+                valid_moves.push(
+                    CandidateBoardPosition {
+                        row_index: row_index as i32,
+                        column_index: king_path_column_indices[1] as i32,
+                    }
+                    .validate_candidate_position_and_unwrap(board),
+                );
+            }
+        }
+
+        valid_moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_get_player {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{king::King, ChessPieceTrait},
+            player::Player,
+        };
+
+        #[test]
+        fn test_get_black_player() {
+            let board: ChessBoard = initialize_empty_board();
+            let king = King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(king.get_player(), &Player::Black);
+        }
+
+        #[test]
+        fn test_get_white_player() {
+            let board: ChessBoard = initialize_empty_board();
+            let king = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(king.get_player(), &Player::White);
+        }
+    }
+
+    mod test_valid_moves_and_captures {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{
+                bishop::Bishop, king::King, knight::Knight, pawn::Pawn, rook::Rook, ChessPiece,
+                ChessPieceTrait, PieceMoveData,
+            },
+            player::Player,
+        };
+
+        #[test]
+        fn test_all_direction_moves_are_available() {
+            let board: ChessBoard = initialize_empty_board();
+            let king: ChessPiece = ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            assert_eq!(
+                king.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_corner_moves_outside_the_board_are_ignored() {
+            let board: ChessBoard = initialize_empty_board();
+            let king: ChessPiece = ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            assert_eq!(
+                king.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_valid_moves_and_captures() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[4][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][4] = Some(ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[2][2] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let king: ChessPiece = ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            assert_eq!(
+                king.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                }
+            );
+        }
+
+        #[test]
+        fn test_no_valid_moves_or_captures() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[6][7] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[6][6] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 6,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[7][6] = Some(ChessPiece::Bishop(Bishop {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let king: ChessPiece = ChessPiece::King(King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            assert_eq!(
+                king.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_castling_move_is_included_in_valid_moves() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let king: ChessPiece = ChessPiece::King(King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            });
+            assert_eq!(
+                king.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+    }
+
+    mod test_valid_castling_moves {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{king::King, knight::Knight, rook::Rook, ChessPiece},
+            player::Player,
+        };
+
+        #[test]
+        fn test_white_kingside_and_queenside_castling_are_valid() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let king: King = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(
+                king.valid_castling_moves(&board),
+                vec![
+                    CandidateBoardPosition {
+                        row_index: 0,
+                        column_index: 6,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    CandidateBoardPosition {
+                        row_index: 0,
+                        column_index: 2,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_black_kingside_and_queenside_castling_are_valid() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[7][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let king: King = King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(
+                king.valid_castling_moves(&board),
+                vec![
+                    CandidateBoardPosition {
+                        row_index: 7,
+                        column_index: 6,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    CandidateBoardPosition {
+                        row_index: 7,
+                        column_index: 2,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_castling_is_invalid_if_king_has_been_moved() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let king: King = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            };
+            assert_eq!(king.valid_castling_moves(&board), vec![]);
+        }
+
+        #[test]
+        fn test_castling_is_invalid_if_rook_has_been_moved() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[7][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let king: King = King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(
+                king.valid_castling_moves(&board),
+                vec![CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),]
+            );
+        }
+
+        #[test]
+        fn test_castling_is_invalid_if_king_is_not_on_its_starting_position() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let king: King = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(king.valid_castling_moves(&board), vec![]);
+        }
+
+        #[test]
+        fn test_castling_is_invalid_without_a_same_player_rook() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let king: King = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(king.valid_castling_moves(&board), vec![]);
+        }
+
+        #[test]
+        fn test_castling_is_invalid_if_a_piece_is_between_king_and_rook() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][1] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            let king: King = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(
+                king.valid_castling_moves(&board),
+                vec![CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),]
+            );
+        }
+
+        #[test]
+        fn test_castling_is_invalid_if_king_is_in_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[5][4] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let king: King = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(king.valid_castling_moves(&board), vec![]);
+        }
+
+        #[test]
+        fn test_castling_is_invalid_if_king_passes_through_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[5][5] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let king: King = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(
+                king.valid_castling_moves(&board),
+                vec![CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),]
+            );
+        }
+
+        #[test]
+        fn test_castling_is_invalid_if_king_lands_in_check() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[7][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[7][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[5][1] = Some(ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            }));
+            let king: King = King {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(
+                king.valid_castling_moves(&board),
+                vec![CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),]
+            );
+        }
+
+        #[test]
+        fn test_queenside_castling_is_valid_if_only_the_rook_passes_an_attacked_position() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[0][0] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[0][7] = Some(ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[5][1] = Some(ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let king: King = King {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            };
+            assert_eq!(
+                king.valid_castling_moves(&board),
+                vec![
+                    CandidateBoardPosition {
+                        row_index: 0,
+                        column_index: 6,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    CandidateBoardPosition {
+                        row_index: 0,
+                        column_index: 2,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                ]
+            );
+        }
+    }
 }
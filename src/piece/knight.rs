@@ -1,10 +1,22 @@
 use crate::{
-    game::board::ChessBoard,
+    game::{board::ChessBoard, board_position::CandidateBoardPosition},
     piece::{BoardPosition, ChessPieceTrait, PieceMoveData},
     player::Player,
 };
 
-#[derive(Debug)]
+// (row_offset, column_offset) pairs for the eight "L" shaped jumps a Knight can make
+pub const KNIGHT_JUMPS: [(i32, i32); 8] = [
+    (2, -1),  // two up, one left
+    (2, 1),   // two up, one right
+    (1, -2),  // one up, two left
+    (1, 2),   // one up, two right
+    (-1, -2), // one down, two left
+    (-1, 2),  // one down, two right
+    (-2, -1), // two down, one left
+    (-2, 1),  // two down, one right
+];
+
+#[derive(Debug, Clone)]
 pub struct Knight {
     pub player: Player,
     pub position: BoardPosition,
@@ -16,13 +28,43 @@ impl ChessPieceTrait for Knight {
     }
 
     fn valid_moves_and_captures(&self, board: &ChessBoard) -> PieceMoveData {
-        todo!();
+        /*
+            Both Black and White Knights move in an "L" shape: two positions in one direction
+            and one position in a perpendicular direction. Unlike every other piece, a knight
+            jumps directly to its destination, so pieces in between never block it.
+
+            Each of the eight jumps is therefore checked on its own. A jump onto an empty
+            position is a move, and a jump onto an opposing piece is a capture.
+        */
+
+        let mut valid_moves: Vec<BoardPosition> = vec![];
+        let mut valid_captures: Vec<BoardPosition> = vec![];
+
+        for (row_offset, column_offset) in KNIGHT_JUMPS.iter() {
+            let position: CandidateBoardPosition = CandidateBoardPosition {
+                row_index: self.position.get_row_index() as i32 + *row_offset,
+                column_index: self.position.get_column_index() as i32 + *column_offset,
+            };
+
+            if let Some(position) = position.validate_candidate_position(board) {
+                if position.is_move_valid(board) {
+                    valid_moves.push(position);
+                } else if position.is_capture_valid(&self.player, board) {
+                    valid_captures.push(position);
+                }
+            }
+        }
+
+        PieceMoveData {
+            valid_moves,
+            valid_captures,
+        }
     }
 
-    fn display_piece_on_board(&self) {
+    fn get_board_symbol(&self) -> &str {
         match self.player {
-            Player::Black => print!("(N)"),
-            Player::White => print!("[N]"),
+            Player::Black => "(N)",
+            Player::White => "[N]",
         }
     }
 
@@ -33,4 +75,393 @@ impl ChessPieceTrait for Knight {
     fn get_board_position(&self) -> &BoardPosition {
         &self.position
     }
+
+    fn set_board_position(&mut self, position: BoardPosition) {
+        self.position = position;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_get_player {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{knight::Knight, ChessPieceTrait},
+            player::Player,
+        };
+
+        #[test]
+        fn test_get_black_player() {
+            let board: ChessBoard = initialize_empty_board();
+            let knight = Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            };
+            assert_eq!(knight.get_player(), &Player::Black);
+        }
+
+        #[test]
+        fn test_get_white_player() {
+            let board: ChessBoard = initialize_empty_board();
+            let knight = Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 1,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            };
+            assert_eq!(knight.get_player(), &Player::White);
+        }
+    }
+
+    mod test_valid_moves_and_captures {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{knight::Knight, pawn::Pawn, ChessPiece, ChessPieceTrait, PieceMoveData},
+            player::Player,
+        };
+
+        #[test]
+        fn test_all_jumps_are_available_from_the_centre() {
+            let board: ChessBoard = initialize_empty_board();
+            let knight: ChessPiece = ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                knight.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_corner_jumps_outside_the_board_are_ignored() {
+            let board: ChessBoard = initialize_empty_board();
+            let knight: ChessPiece = ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                knight.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_edge_jumps_outside_the_board_are_ignored() {
+            let board: ChessBoard = initialize_empty_board();
+            let knight: ChessPiece = ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                knight.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_knight_jumps_over_surrounding_pieces() {
+            let mut board: ChessBoard = initialize_empty_board();
+            for (row_index, column_index) in [(1, 0), (1, 1), (0, 1)] {
+                board[row_index][column_index] = Some(ChessPiece::Pawn(Pawn {
+                    player: Player::White,
+                    position: CandidateBoardPosition {
+                        row_index: row_index as i32,
+                        column_index: column_index as i32,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    has_been_moved: false,
+                }));
+            }
+            let knight: ChessPiece = ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                knight.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_same_player_pieces_block_jumps() {
+            let mut board: ChessBoard = initialize_empty_board();
+            for (row_index, column_index) in [(2, 1), (1, 2)] {
+                board[row_index][column_index] = Some(ChessPiece::Pawn(Pawn {
+                    player: Player::Black,
+                    position: CandidateBoardPosition {
+                        row_index: row_index as i32,
+                        column_index: column_index as i32,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    has_been_moved: true,
+                }));
+            }
+            let knight: ChessPiece = ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                knight.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_opposing_player_pieces_are_captures() {
+            let mut board: ChessBoard = initialize_empty_board();
+            for (row_index, column_index) in [(2, 1), (1, 2)] {
+                board[row_index][column_index] = Some(ChessPiece::Pawn(Pawn {
+                    player: Player::White,
+                    position: CandidateBoardPosition {
+                        row_index: row_index as i32,
+                        column_index: column_index as i32,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    has_been_moved: true,
+                }));
+            }
+            let knight: ChessPiece = ChessPiece::Knight(Knight {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                knight.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![],
+                    valid_captures: vec![
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                }
+            );
+        }
+
+        #[test]
+        fn test_valid_moves_and_captures() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[5][6] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: false,
+            }));
+            board[5][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let knight: ChessPiece = ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                knight.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 7,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![CandidateBoardPosition {
+                        row_index: 5,
+                        column_index: 6,
+                    }
+                    .validate_candidate_position_and_unwrap(&board)],
+                }
+            );
+        }
+
+        #[test]
+        fn test_no_valid_moves_or_captures() {
+            let mut board: ChessBoard = initialize_empty_board();
+            for (row_index, column_index) in [(5, 6), (6, 5)] {
+                board[row_index][column_index] = Some(ChessPiece::Pawn(Pawn {
+                    player: Player::White,
+                    position: CandidateBoardPosition {
+                        row_index: row_index as i32,
+                        column_index: column_index as i32,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    has_been_moved: true,
+                }));
+            }
+            let knight: ChessPiece = ChessPiece::Knight(Knight {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 7,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                knight.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![],
+                    valid_captures: vec![],
+                }
+            );
+        }
+    }
 }
@@ -4,8 +4,7 @@ use crate::{
     player::Player,
 };
 
-// NOTE: We will not be implementing Pawn promotion or the En Passant capture rule for simplicity
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Pawn {
     pub player: Player,
     pub position: BoardPosition,
@@ -18,16 +17,13 @@ impl ChessPieceTrait for Pawn {
     }
 
     fn valid_moves_and_captures(&self, board: &ChessBoard) -> PieceMoveData {
-        PieceMoveData {
-            valid_moves: self.valid_moves(&board),
-            valid_captures: self.valid_captures(&board),
-        }
+        self.valid_moves_and_captures_with_en_passant(board, None)
     }
 
-    fn display_piece_on_board(&self) {
+    fn get_board_symbol(&self) -> &str {
         match self.player {
-            Player::Black => print!("(P)"),
-            Player::White => print!("[P]"),
+            Player::Black => "(P)",
+            Player::White => "[P]",
         }
     }
 
@@ -38,9 +34,37 @@ impl ChessPieceTrait for Pawn {
     fn get_board_position(&self) -> &BoardPosition {
         &self.position
     }
+
+    fn set_board_position(&mut self, position: BoardPosition) {
+        self.position = position;
+    }
 }
 
 impl Pawn {
+    /*
+        Same as `valid_moves_and_captures`, but also given the en passant target: the position an
+        opposing pawn skipped over by moving two positions forward on the previous turn. The board
+        alone cannot tell us whether that happened, so the ChessGame keeps track of it.
+    */
+    pub fn valid_moves_and_captures_with_en_passant(
+        &self,
+        board: &ChessBoard,
+        en_passant_target: Option<&BoardPosition>,
+    ) -> PieceMoveData {
+        PieceMoveData {
+            valid_moves: self.valid_moves(board),
+            valid_captures: self.valid_captures(board, en_passant_target),
+        }
+    }
+
+    // A pawn reaching the opposing player's back row is promoted
+    pub fn get_promotion_row_index(&self) -> usize {
+        match self.player {
+            Player::White => 7,
+            Player::Black => 0,
+        }
+    }
+
     fn valid_moves(&self, board: &ChessBoard) -> Vec<BoardPosition> {
         let mut valid_moves: Vec<BoardPosition> = vec![];
         let pawn_direction: i32 = match self.player {
@@ -54,8 +78,8 @@ impl Pawn {
         };
 
         if let Some(normal_move_position) = normal_move
-            .validate_candidate_position(&board)
-            .filter(|position| position.is_move_valid(&board))
+            .validate_candidate_position(board)
+            .filter(|position| position.is_move_valid(board))
         {
             valid_moves.push(normal_move_position);
 
@@ -71,8 +95,8 @@ impl Pawn {
                 };
 
                 if let Some(initial_two_move_position) = initial_two_move
-                    .validate_candidate_position(&board)
-                    .filter(|position| position.is_move_valid(&board))
+                    .validate_candidate_position(board)
+                    .filter(|position| position.is_move_valid(board))
                 {
                     valid_moves.push(initial_two_move_position);
                 }
@@ -82,7 +106,11 @@ impl Pawn {
         valid_moves
     }
 
-    fn valid_captures(&self, board: &ChessBoard) -> Vec<BoardPosition> {
+    fn valid_captures(
+        &self,
+        board: &ChessBoard,
+        en_passant_target: Option<&BoardPosition>,
+    ) -> Vec<BoardPosition> {
         let mut valid_captures: Vec<BoardPosition> = vec![];
         let pawn_direction: i32 = match self.player {
             Player::White => 1,
@@ -95,8 +123,8 @@ impl Pawn {
         };
 
         if let Some(board_position) = left_diagonal_capture
-            .validate_candidate_position(&board)
-            .filter(|position| position.is_capture_valid(&self.player, &board))
+            .validate_candidate_position(board)
+            .filter(|position| position.is_capture_valid(&self.player, board))
         {
             valid_captures.push(board_position);
         }
@@ -106,12 +134,27 @@ impl Pawn {
             column_index: self.position.get_column_index() as i32 + 1,
         };
         if let Some(board_position) = right_diagonal_capture
-            .validate_candidate_position(&board)
-            .filter(|position| position.is_capture_valid(&self.player, &board))
+            .validate_candidate_position(board)
+            .filter(|position| position.is_capture_valid(&self.player, board))
         {
             valid_captures.push(board_position);
         }
 
+        /*
+            En passant: the pawn captures diagonally onto the (empty) position the opposing pawn
+            skipped over, as if that pawn had only moved one position forward.
+        */
+        if let Some(en_passant_target) = en_passant_target.filter(|en_passant_target| {
+            en_passant_target.get_row_index() as i32
+                == self.position.get_row_index() as i32 + pawn_direction
+                && en_passant_target
+                    .get_column_index()
+                    .abs_diff(self.position.get_column_index())
+                    == 1
+        }) {
+            valid_captures.push(*en_passant_target);
+        }
+
         valid_captures
     }
 }
@@ -579,7 +622,7 @@ mod tests {
         use crate::{
             game::{
                 board::{initialize_empty_board, ChessBoard},
-                board_position::CandidateBoardPosition,
+                board_position::{BoardPosition, CandidateBoardPosition},
             },
             piece::{pawn::Pawn, ChessPiece},
             player::Player,
@@ -607,7 +650,7 @@ mod tests {
                 has_been_moved: true,
             };
             assert_eq!(
-                pawn.valid_captures(&board),
+                pawn.valid_captures(&board, None),
                 vec![CandidateBoardPosition {
                     row_index: 3,
                     column_index: 3,
@@ -638,7 +681,7 @@ mod tests {
                 has_been_moved: true,
             };
             assert_eq!(
-                pawn.valid_captures(&board),
+                pawn.valid_captures(&board, None),
                 vec![CandidateBoardPosition {
                     row_index: 3,
                     column_index: 5,
@@ -678,7 +721,7 @@ mod tests {
                 has_been_moved: true,
             };
             assert_eq!(
-                pawn.valid_captures(&board),
+                pawn.valid_captures(&board, None),
                 vec![
                     CandidateBoardPosition {
                         row_index: 3,
@@ -706,7 +749,7 @@ mod tests {
                 .validate_candidate_position_and_unwrap(&board),
                 has_been_moved: true,
             };
-            assert_eq!(pawn.valid_captures(&board), vec![]);
+            assert_eq!(pawn.valid_captures(&board, None), vec![]);
         }
 
         #[test]
@@ -730,7 +773,7 @@ mod tests {
                 .validate_candidate_position_and_unwrap(&board),
                 has_been_moved: true,
             };
-            assert_eq!(pawn.valid_captures(&board), vec![]);
+            assert_eq!(pawn.valid_captures(&board, None), vec![]);
         }
 
         #[test]
@@ -745,7 +788,7 @@ mod tests {
                 .validate_candidate_position_and_unwrap(&board),
                 has_been_moved: true,
             };
-            assert_eq!(pawn.valid_captures(&board), vec![])
+            assert_eq!(pawn.valid_captures(&board, None), vec![])
         }
 
         #[test]
@@ -770,7 +813,7 @@ mod tests {
                 has_been_moved: true,
             };
             assert_eq!(
-                pawn.valid_captures(&board),
+                pawn.valid_captures(&board, None),
                 vec![CandidateBoardPosition {
                     row_index: 5,
                     column_index: 4,
@@ -801,7 +844,7 @@ mod tests {
                 has_been_moved: true,
             };
             assert_eq!(
-                pawn.valid_captures(&board),
+                pawn.valid_captures(&board, None),
                 vec![CandidateBoardPosition {
                     row_index: 5,
                     column_index: 6,
@@ -841,7 +884,7 @@ mod tests {
                 has_been_moved: true,
             };
             assert_eq!(
-                pawn.valid_captures(&board),
+                pawn.valid_captures(&board, None),
                 vec![
                     CandidateBoardPosition {
                         row_index: 5,
@@ -869,7 +912,7 @@ mod tests {
                 .validate_candidate_position_and_unwrap(&board),
                 has_been_moved: true,
             };
-            assert_eq!(pawn.valid_captures(&board), vec![]);
+            assert_eq!(pawn.valid_captures(&board, None), vec![]);
         }
 
         #[test]
@@ -893,7 +936,7 @@ mod tests {
                 .validate_candidate_position_and_unwrap(&board),
                 has_been_moved: true,
             };
-            assert_eq!(pawn.valid_captures(&board), vec![]);
+            assert_eq!(pawn.valid_captures(&board, None), vec![]);
         }
 
         #[test]
@@ -908,7 +951,110 @@ mod tests {
                 .validate_candidate_position_and_unwrap(&board),
                 has_been_moved: true,
             };
-            assert_eq!(pawn.valid_captures(&board), vec![])
+            assert_eq!(pawn.valid_captures(&board, None), vec![])
+        }
+
+        #[test]
+        fn test_white_en_passant_capture_is_valid() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[4][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let pawn: Pawn = Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            };
+            let en_passant_target: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert_eq!(
+                pawn.valid_captures(&board, Some(&en_passant_target)),
+                vec![en_passant_target]
+            );
+        }
+
+        #[test]
+        fn test_black_en_passant_capture_is_valid_alongside_normal_capture() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[2][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let pawn: Pawn = Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            };
+            let en_passant_target: BoardPosition = CandidateBoardPosition {
+                row_index: 2,
+                column_index: 4,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert_eq!(
+                pawn.valid_captures(&board, Some(&en_passant_target)),
+                vec![
+                    CandidateBoardPosition {
+                        row_index: 2,
+                        column_index: 2,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    en_passant_target
+                ]
+            );
+        }
+
+        #[test]
+        fn test_en_passant_target_out_of_reach_is_ignored() {
+            let board: ChessBoard = initialize_empty_board();
+            let pawn: Pawn = Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            };
+            let en_passant_target: BoardPosition = CandidateBoardPosition {
+                row_index: 5,
+                column_index: 3,
+            }
+            .validate_candidate_position_and_unwrap(&board);
+            assert_eq!(
+                pawn.valid_captures(&board, Some(&en_passant_target)),
+                vec![]
+            );
         }
     }
 }
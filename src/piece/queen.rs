@@ -1,10 +1,13 @@
 use crate::{
     game::board::ChessBoard,
-    piece::{BoardPosition, ChessPieceTrait, PieceMoveData},
+    piece::{
+        sliding_moves_and_captures, BoardPosition, ChessPieceTrait, PieceMoveData,
+        DIAGONAL_DIRECTIONS, ORTHOGONAL_DIRECTIONS,
+    },
     player::Player,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Queen {
     pub player: Player,
     pub position: BoardPosition,
@@ -16,13 +19,18 @@ impl ChessPieceTrait for Queen {
     }
 
     fn valid_moves_and_captures(&self, board: &ChessBoard) -> PieceMoveData {
-        todo!();
+        /*
+            A Queen combines the movement of a Rook and a Bishop, sliding in all four
+            orthogonal and all four diagonal directions until it is blocked.
+        */
+        let directions: Vec<(i32, i32)> = [ORTHOGONAL_DIRECTIONS, DIAGONAL_DIRECTIONS].concat();
+        sliding_moves_and_captures(&self.player, &self.position, &directions, board)
     }
 
-    fn display_piece_on_board(&self) {
+    fn get_board_symbol(&self) -> &str {
         match self.player {
-            Player::Black => print!("(Q)"),
-            Player::White => print!("[Q]"),
+            Player::Black => "(Q)",
+            Player::White => "[Q]",
         }
     }
 
@@ -33,4 +41,697 @@ impl ChessPieceTrait for Queen {
     fn get_board_position(&self) -> &BoardPosition {
         &self.position
     }
+
+    fn set_board_position(&mut self, position: BoardPosition) {
+        self.position = position;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_get_player {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{queen::Queen, ChessPieceTrait},
+            player::Player,
+        };
+
+        #[test]
+        fn test_get_black_player() {
+            let board: ChessBoard = initialize_empty_board();
+            let queen = Queen {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            };
+            assert_eq!(queen.get_player(), &Player::Black);
+        }
+
+        #[test]
+        fn test_get_white_player() {
+            let board: ChessBoard = initialize_empty_board();
+            let queen = Queen {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            };
+            assert_eq!(queen.get_player(), &Player::White);
+        }
+    }
+
+    mod test_valid_moves_and_captures {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{pawn::Pawn, queen::Queen, ChessPiece, ChessPieceTrait, PieceMoveData},
+            player::Player,
+        };
+
+        #[test]
+        fn test_corner_moves_are_available() {
+            let board: ChessBoard = initialize_empty_board();
+            let queen: ChessPiece = ChessPiece::Queen(Queen {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                queen.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 7,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 7,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 7,
+                            column_index: 7,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_all_direction_moves_are_available() {
+            let board: ChessBoard = initialize_empty_board();
+            let queen: ChessPiece = ChessPiece::Queen(Queen {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                queen.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 7,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 7,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 6,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 7,
+                            column_index: 7,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_same_player_pieces_block_all_directions() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[4][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let queen: ChessPiece = ChessPiece::Queen(Queen {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                queen.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_opposing_player_pieces_are_captured_in_all_directions() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[4][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let queen: ChessPiece = ChessPiece::Queen(Queen {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                queen.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![],
+                    valid_captures: vec![
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                }
+            );
+        }
+
+        #[test]
+        fn test_valid_moves_and_captures() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[5][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][0] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][3] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[2][2] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 2,
+                    column_index: 2,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[1][5] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 1,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let queen: ChessPiece = ChessPiece::Queen(Queen {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            });
+            assert_eq!(
+                queen.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 2,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                    valid_captures: vec![
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 3,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 3,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 1,
+                            column_index: 5,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                }
+            );
+        }
+    }
 }
@@ -1,13 +1,18 @@
 use crate::{
     game::board::ChessBoard,
-    piece::{BoardPosition, ChessPieceTrait, PieceMoveData},
+    piece::{
+        sliding_moves_and_captures, BoardPosition, ChessPieceTrait, PieceMoveData,
+        ORTHOGONAL_DIRECTIONS,
+    },
     player::Player,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Rook {
     pub player: Player,
     pub position: BoardPosition,
+    // A Rook that has been moved can no longer be castled with
+    pub has_been_moved: bool,
 }
 
 impl ChessPieceTrait for Rook {
@@ -16,13 +21,17 @@ impl ChessPieceTrait for Rook {
     }
 
     fn valid_moves_and_captures(&self, board: &ChessBoard) -> PieceMoveData {
-        todo!();
+        /*
+            Both Black and White Rooks can move up, down, left and right across the board,
+            so just like Bishops we do not need a direction variable based on the player.
+        */
+        sliding_moves_and_captures(&self.player, &self.position, &ORTHOGONAL_DIRECTIONS, board)
     }
 
-    fn display_piece_on_board(&self) {
+    fn get_board_symbol(&self) -> &str {
         match self.player {
-            Player::Black => print!("(R)"),
-            Player::White => print!("[R]"),
+            Player::Black => "(R)",
+            Player::White => "[R]",
         }
     }
 
@@ -33,4 +42,336 @@ impl ChessPieceTrait for Rook {
     fn get_board_position(&self) -> &BoardPosition {
         &self.position
     }
+
+    fn set_board_position(&mut self, position: BoardPosition) {
+        self.position = position;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_get_player {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::CandidateBoardPosition,
+            },
+            piece::{rook::Rook, ChessPieceTrait},
+            player::Player,
+        };
+
+        #[test]
+        fn test_get_black_player() {
+            let board: ChessBoard = initialize_empty_board();
+            let rook = Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            };
+            assert_eq!(rook.get_player(), &Player::Black);
+        }
+
+        #[test]
+        fn test_get_white_player() {
+            let board: ChessBoard = initialize_empty_board();
+            let rook = Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            };
+            assert_eq!(rook.get_player(), &Player::White);
+        }
+    }
+
+    mod test_valid_moves_and_captures {
+        use crate::{
+            game::{
+                board::{initialize_empty_board, ChessBoard},
+                board_position::{BoardPosition, CandidateBoardPosition},
+            },
+            piece::{pawn::Pawn, rook::Rook, ChessPiece, ChessPieceTrait, PieceMoveData},
+            player::Player,
+        };
+
+        #[test]
+        fn test_corner_moves_are_available() {
+            let board: ChessBoard = initialize_empty_board();
+            let rook: ChessPiece = ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            let upward_moves = (1..=7).map(|row_index| {
+                CandidateBoardPosition {
+                    row_index,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board)
+            });
+            let right_moves = (1..=7).map(|column_index| {
+                CandidateBoardPosition {
+                    row_index: 0,
+                    column_index,
+                }
+                .validate_candidate_position_and_unwrap(&board)
+            });
+            assert_eq!(
+                rook.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: upward_moves.chain(right_moves).collect(),
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_edge_moves_are_available() {
+            let board: ChessBoard = initialize_empty_board();
+            let rook: ChessPiece = ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 7,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            let downward_moves = (0..=6).rev().map(|row_index| {
+                CandidateBoardPosition {
+                    row_index,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board)
+            });
+            let left_moves = (0..=2).rev().map(|column_index| {
+                CandidateBoardPosition {
+                    row_index: 7,
+                    column_index,
+                }
+                .validate_candidate_position_and_unwrap(&board)
+            });
+            let right_moves = (4..=7).map(|column_index| {
+                CandidateBoardPosition {
+                    row_index: 7,
+                    column_index,
+                }
+                .validate_candidate_position_and_unwrap(&board)
+            });
+            assert_eq!(
+                rook.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: downward_moves
+                        .chain(left_moves)
+                        .chain(right_moves)
+                        .collect(),
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_same_player_pieces_block_moves() {
+            let mut board: ChessBoard = initialize_empty_board();
+            for (row_index, column_index) in [(4, 3), (2, 3), (3, 2), (3, 5)] {
+                board[row_index][column_index] = Some(ChessPiece::Pawn(Pawn {
+                    player: Player::White,
+                    position: CandidateBoardPosition {
+                        row_index: row_index as i32,
+                        column_index: column_index as i32,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    has_been_moved: true,
+                }));
+            }
+            let rook: ChessPiece = ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 3,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            assert_eq!(
+                rook.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![CandidateBoardPosition {
+                        row_index: 3,
+                        column_index: 4,
+                    }
+                    .validate_candidate_position_and_unwrap(&board)],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_all_direction_moves_are_blocked() {
+            let mut board: ChessBoard = initialize_empty_board();
+            for (row_index, column_index) in [(1, 0), (0, 1)] {
+                board[row_index][column_index] = Some(ChessPiece::Pawn(Pawn {
+                    player: Player::Black,
+                    position: CandidateBoardPosition {
+                        row_index: row_index as i32,
+                        column_index: column_index as i32,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    has_been_moved: true,
+                }));
+            }
+            let rook: ChessPiece = ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            assert_eq!(
+                rook.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![],
+                    valid_captures: vec![],
+                }
+            );
+        }
+
+        #[test]
+        fn test_first_opposing_piece_is_captured() {
+            let mut board: ChessBoard = initialize_empty_board();
+            // The pawn at (6, 0) sits behind the pawn at (2, 0) and cannot be reached
+            for (row_index, column_index) in [(2, 0), (6, 0), (0, 1)] {
+                board[row_index][column_index] = Some(ChessPiece::Pawn(Pawn {
+                    player: Player::Black,
+                    position: CandidateBoardPosition {
+                        row_index: row_index as i32,
+                        column_index: column_index as i32,
+                    }
+                    .validate_candidate_position_and_unwrap(&board),
+                    has_been_moved: true,
+                }));
+            }
+            let rook: ChessPiece = ChessPiece::Rook(Rook {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 0,
+                    column_index: 0,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            assert_eq!(
+                rook.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves: vec![CandidateBoardPosition {
+                        row_index: 1,
+                        column_index: 0,
+                    }
+                    .validate_candidate_position_and_unwrap(&board)],
+                    valid_captures: vec![
+                        CandidateBoardPosition {
+                            row_index: 2,
+                            column_index: 0,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 0,
+                            column_index: 1,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                }
+            );
+        }
+
+        #[test]
+        fn test_valid_moves_and_captures() {
+            let mut board: ChessBoard = initialize_empty_board();
+            board[5][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 5,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[3][4] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 3,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            board[4][6] = Some(ChessPiece::Pawn(Pawn {
+                player: Player::White,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 6,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            }));
+            let rook: ChessPiece = ChessPiece::Rook(Rook {
+                player: Player::Black,
+                position: CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 4,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+                has_been_moved: true,
+            });
+            let left_moves: Vec<BoardPosition> = (0..=3)
+                .rev()
+                .map(|column_index| {
+                    CandidateBoardPosition {
+                        row_index: 4,
+                        column_index,
+                    }
+                    .validate_candidate_position_and_unwrap(&board)
+                })
+                .collect();
+            let mut valid_moves: Vec<BoardPosition> = left_moves;
+            valid_moves.push(
+                CandidateBoardPosition {
+                    row_index: 4,
+                    column_index: 5,
+                }
+                .validate_candidate_position_and_unwrap(&board),
+            );
+            assert_eq!(
+                rook.valid_moves_and_captures(&board),
+                PieceMoveData {
+                    valid_moves,
+                    valid_captures: vec![
+                        CandidateBoardPosition {
+                            row_index: 5,
+                            column_index: 4,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                        CandidateBoardPosition {
+                            row_index: 4,
+                            column_index: 6,
+                        }
+                        .validate_candidate_position_and_unwrap(&board),
+                    ],
+                }
+            );
+        }
+    }
 }
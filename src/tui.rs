@@ -0,0 +1,720 @@
+use crate::{
+    game::{
+        board::ChessBoard,
+        board_position::{BoardPosition, CandidateBoardPosition},
+        chess_move::{LegalMove, PromotionPiece},
+        clock::format_duration,
+        game_status::GameStatus,
+        render::{get_piece_glyph, BoardView, RenderOptions, SquareHighlight},
+        ChessGame,
+    },
+    piece::{ChessPiece, ChessPieceTrait},
+    player::Player,
+};
+use ratatui::{
+    crossterm::{
+        event::{
+            self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind,
+            MouseButton, MouseEventKind,
+        },
+        execute,
+    },
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    DefaultTerminal, Frame,
+};
+use std::{
+    collections::HashMap,
+    io,
+    time::{Duration, Instant},
+};
+
+// Each square is drawn as a block of characters, with the piece in the middle of its last line
+const SQUARE_WIDTH: u16 = 5;
+const SQUARE_HEIGHT: u16 = 2;
+// The row labels to the left of the board, e.g. "8 "
+const ROW_LABEL_WIDTH: u16 = 2;
+// How often the clocks are redrawn while waiting for input
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+const LIGHT_SQUARE_COLOUR: Color = Color::Rgb(240, 217, 181);
+const DARK_SQUARE_COLOUR: Color = Color::Rgb(181, 136, 99);
+const LAST_MOVE_COLOUR: Color = Color::Rgb(205, 210, 106);
+const LEGAL_DESTINATION_COLOUR: Color = Color::Rgb(130, 170, 110);
+const CHECK_COLOUR: Color = Color::Rgb(220, 90, 80);
+const CURSOR_COLOUR: Color = Color::Rgb(110, 160, 220);
+
+/*
+    Plays `game` in a full-screen view until the players quit it, after which the terminal is
+    restored and the game can carry on in the prompts. Squares are picked with the arrow keys
+    and Enter, or by clicking them: first the piece to move, then where to move it.
+*/
+pub fn run(game: &mut ChessGame) -> io::Result<()> {
+    let mut terminal: DefaultTerminal = ratatui::init();
+    // The terminal is restored however the view ends, before any error is returned
+    let result: io::Result<()> = execute!(io::stdout(), EnableMouseCapture)
+        .and_then(|()| FullScreenView::new(game).run(&mut terminal));
+    let disable_mouse_capture_result: io::Result<()> = execute!(io::stdout(), DisableMouseCapture);
+    ratatui::restore();
+    result.and(disable_mouse_capture_result)
+}
+
+struct FullScreenView<'a> {
+    game: &'a mut ChessGame,
+    // The player whose pieces are at the bottom of the board
+    perspective: Player,
+    cursor: BoardPosition,
+    selected_position: Option<BoardPosition>,
+    // A pawn move that is waiting for the piece to promote to
+    pending_promotion: Option<(BoardPosition, BoardPosition)>,
+    message: String,
+    // The moves made so far in SAN, kept up to date as moves are made and taken back, as
+    // writing them from scratch means replaying the whole game
+    san_moves: Vec<String>,
+    // How long each player has spent on their moves while the view was open
+    time_used: HashMap<Player, Duration>,
+    turn_started_at: Instant,
+    // Where the board was last drawn, for working out which square was clicked
+    board_area: Rect,
+    should_quit: bool,
+}
+
+impl<'a> FullScreenView<'a> {
+    fn new(game: &'a mut ChessGame) -> FullScreenView<'a> {
+        let perspective: Player = game.get_current_player();
+        let cursor: BoardPosition = *game
+            .board_data
+            .get_king_position(&perspective)
+            .unwrap_or_else(|| panic!("Player {} has no king", perspective));
+        let san_moves: Vec<String> = game.get_san_move_list();
+        FullScreenView {
+            game,
+            perspective,
+            cursor,
+            selected_position: None,
+            pending_promotion: None,
+            message: String::new(),
+            san_moves,
+            time_used: HashMap::from([
+                (Player::White, Duration::ZERO),
+                (Player::Black, Duration::ZERO),
+            ]),
+            turn_started_at: Instant::now(),
+            board_area: Rect::default(),
+            should_quit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.should_quit {
+            self.game.check_flag_fall();
+            terminal.draw(|frame| self.draw(frame))?;
+            if !event::poll(TICK_RATE)? {
+                continue;
+            }
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => self.handle_key(key.code),
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    if let Some(position) = get_square_at(
+                        &self.game.board_data.board,
+                        self.board_area,
+                        &self.perspective,
+                        mouse.column,
+                        mouse.row,
+                    ) {
+                        self.cursor = position;
+                        self.activate_square(position);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_key(&mut self, key_code: KeyCode) {
+        if let Some((from, to)) = self.pending_promotion {
+            let promotion: Option<PromotionPiece> = match key_code {
+                KeyCode::Char(letter) => PromotionPiece::ALL
+                    .into_iter()
+                    .find(|promotion| promotion.get_piece_letter() == letter.to_ascii_uppercase()),
+                _ => None,
+            };
+            match (promotion, key_code) {
+                (Some(promotion), _) => self.play_move(LegalMove {
+                    from,
+                    to,
+                    promotion: Some(promotion),
+                }),
+                (None, KeyCode::Esc) => {
+                    self.pending_promotion = None;
+                    self.message = String::new();
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Up is always towards the top of the screen, whichever side the board is seen from
+        let (up, right): (i32, i32) = match self.perspective {
+            Player::White => (1, 1),
+            Player::Black => (-1, -1),
+        };
+        match key_code {
+            KeyCode::Up => self.move_cursor(up, 0),
+            KeyCode::Down => self.move_cursor(-up, 0),
+            KeyCode::Left => self.move_cursor(0, -right),
+            KeyCode::Right => self.move_cursor(0, right),
+            KeyCode::Enter | KeyCode::Char(' ') => self.activate_square(self.cursor),
+            KeyCode::Esc => {
+                self.selected_position = None;
+                self.message = String::new();
+            }
+            KeyCode::Char('u' | 'r') if self.game.clock.is_some() => {
+                self.message = "Moves can not be taken back in a timed game".to_string();
+            }
+            KeyCode::Char('u') => {
+                self.record_time_used();
+                self.selected_position = None;
+                self.message = match self.game.undo_move() {
+                    Some(chess_move) => {
+                        self.san_moves.pop();
+                        format!("Undid: {}", chess_move)
+                    }
+                    None => "There is no move to undo".to_string(),
+                };
+            }
+            KeyCode::Char('r') => {
+                self.record_time_used();
+                self.selected_position = None;
+                // Writing the move in SAN needs the game as it was before the move
+                let game_before_move: ChessGame = self.game.clone();
+                self.message = match self.game.redo_move() {
+                    Some(chess_move) => {
+                        self.san_moves.push(chess_move.to_san(&game_before_move));
+                        format!("Redid: {}", chess_move)
+                    }
+                    None => "There is no move to redo".to_string(),
+                };
+            }
+            KeyCode::Char('f') => self.perspective = self.perspective.get_opponent(),
+            KeyCode::Char('q') => self.should_quit = true,
+            _ => {}
+        }
+    }
+
+    fn move_cursor(&mut self, row_offset: i32, column_offset: i32) {
+        if let Some(position) = (CandidateBoardPosition {
+            row_index: self.cursor.get_row_index() as i32 + row_offset,
+            column_index: self.cursor.get_column_index() as i32 + column_offset,
+        })
+        .validate_candidate_position(&self.game.board_data.board)
+        {
+            self.cursor = position;
+        }
+    }
+
+    /*
+        Picks the square, either as the piece to move or as where to move the selected piece.
+        Picking another piece of the player to move selects it instead, and picking the
+        selected piece again deselects it.
+    */
+    fn activate_square(&mut self, position: BoardPosition) {
+        if self.pending_promotion.is_some() {
+            return;
+        }
+        let player: Player = self.game.get_current_player();
+        let legal_move_list: Vec<LegalMove> = self.game.legal_move_list(&player);
+
+        if let Some(from) = self.selected_position {
+            let legal_moves: Vec<&LegalMove> = legal_move_list
+                .iter()
+                .filter(|legal_move| legal_move.from == from && legal_move.to == position)
+                .collect();
+            match legal_moves.as_slice() {
+                [] => {}
+                [legal_move] => return self.play_move(**legal_move),
+                // The same pawn move with each of the pieces it can be promoted to
+                _ => {
+                    self.pending_promotion = Some((from, position));
+                    self.message =
+                        "Promote to a (q)ueen, (r)ook, (b)ishop or k(n)ight? Esc cancels"
+                            .to_string();
+                    return;
+                }
+            }
+            if from == position {
+                self.selected_position = None;
+                return;
+            }
+        }
+
+        if legal_move_list
+            .iter()
+            .any(|legal_move| legal_move.from == position)
+        {
+            self.selected_position = Some(position);
+            self.message = String::new();
+        } else {
+            self.message = match &self.game.status {
+                GameStatus::Finished(result) => result.to_string(),
+                _ => format!(
+                    "There is no piece on {} that Player {} can move",
+                    position, player
+                ),
+            };
+        }
+    }
+
+    fn play_move(&mut self, legal_move: LegalMove) {
+        self.record_time_used();
+        self.selected_position = None;
+        self.pending_promotion = None;
+        let game_before_move: ChessGame = self.game.clone();
+        self.message = match self.game.make_move_with_promotion(
+            &legal_move.from,
+            &legal_move.to,
+            legal_move.promotion,
+        ) {
+            Ok(chess_move) => {
+                self.san_moves.push(chess_move.to_san(&game_before_move));
+                chess_move.to_string()
+            }
+            Err(err) => err.to_string(),
+        };
+    }
+
+    // Adds the time since the turn started to the player to move, before the turn changes
+    fn record_time_used(&mut self) {
+        let now: Instant = Instant::now();
+        *self
+            .time_used
+            .entry(self.game.get_current_player())
+            .or_default() += now - self.turn_started_at;
+        self.turn_started_at = now;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main_area, status_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(frame.area());
+        let board_width: u16 = ROW_LABEL_WIDTH + SQUARE_WIDTH * 8 + 2;
+        let [board_area, side_area] =
+            Layout::horizontal([Constraint::Length(board_width), Constraint::Min(20)])
+                .areas(main_area);
+        let [move_list_area, captured_area, clock_area] = Layout::vertical([
+            Constraint::Min(3),
+            Constraint::Length(4),
+            Constraint::Length(4),
+        ])
+        .areas(side_area);
+
+        self.draw_board(frame, board_area);
+        self.draw_move_list(frame, move_list_area);
+        self.draw_captured_pieces(frame, captured_area);
+        self.draw_clocks(frame, clock_area);
+        self.draw_status_bar(frame, status_area);
+    }
+
+    fn draw_board(&mut self, frame: &mut Frame, area: Rect) {
+        let block: Block = Block::bordered().title(" Board ");
+        self.board_area = block.inner(area);
+        let view: BoardView = self.game.get_board_view(&RenderOptions {
+            perspective: self.perspective,
+            selected_position: self.selected_position,
+            ..RenderOptions::default()
+        });
+
+        let mut lines: Vec<Line> = vec![];
+        for row in view.rows.iter() {
+            for line_index in 0..SQUARE_HEIGHT {
+                let is_piece_line: bool = line_index == SQUARE_HEIGHT - 1;
+                let row_label: String = match (is_piece_line, row.first()) {
+                    (true, Some(square)) => format!("{} ", square.position.get_row_index() + 1),
+                    _ => " ".repeat(ROW_LABEL_WIDTH as usize),
+                };
+                let mut spans: Vec<Span> = vec![Span::raw(row_label)];
+                for square in row.iter() {
+                    let background: Color = if square.position == self.cursor {
+                        CURSOR_COLOUR
+                    } else if Some(square.position) == self.selected_position {
+                        LAST_MOVE_COLOUR
+                    } else {
+                        match square.highlight {
+                            Some(SquareHighlight::Check) => CHECK_COLOUR,
+                            Some(SquareHighlight::LegalDestination) => LEGAL_DESTINATION_COLOUR,
+                            Some(SquareHighlight::LastMove) => LAST_MOVE_COLOUR,
+                            None if square.is_light() => LIGHT_SQUARE_COLOUR,
+                            None => DARK_SQUARE_COLOUR,
+                        }
+                    };
+                    let (text, foreground): (String, Color) = match square.piece {
+                        Some(piece) if is_piece_line => (
+                            format!("  {}  ", get_piece_glyph(piece, true)),
+                            match piece.get_player() {
+                                Player::White => Color::White,
+                                Player::Black => Color::Black,
+                            },
+                        ),
+                        _ => (" ".repeat(SQUARE_WIDTH as usize), Color::Reset),
+                    };
+                    spans.push(Span::styled(
+                        text,
+                        Style::new()
+                            .bg(background)
+                            .fg(foreground)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                }
+                lines.push(Line::from(spans));
+            }
+        }
+        let column_labels: String = view.rows.first().map_or(String::new(), |row| {
+            row.iter()
+                .map(|square| format!("  {}  ", square.position.get_column_letter()))
+                .collect()
+        });
+        lines.push(Line::raw(format!(
+            "{}{}",
+            " ".repeat(ROW_LABEL_WIDTH as usize),
+            column_labels
+        )));
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn draw_move_list(&self, frame: &mut Frame, area: Rect) {
+        let first_turn: u32 = self
+            .game
+            .move_history
+            .first()
+            .map_or(self.game.turn, |history_entry| history_entry.turn);
+        let move_list_lines: Vec<String> = get_move_list_lines(&self.san_moves, first_turn);
+        // Keep the latest moves in view
+        let visible_line_count: usize = area.height.saturating_sub(2) as usize;
+        let lines: Vec<Line> = move_list_lines
+            .iter()
+            .skip(move_list_lines.len().saturating_sub(visible_line_count))
+            .map(|line| Line::raw(line.as_str()))
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(" Moves ")),
+            area,
+        );
+    }
+
+    fn draw_captured_pieces(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = [Player::White, Player::Black]
+            .iter()
+            .map(|player| {
+                let captured_pieces: Vec<&ChessPiece> = get_captured_pieces(self.game, player);
+                let glyphs: String = captured_pieces
+                    .iter()
+                    .map(|piece| get_piece_glyph(piece, false))
+                    .collect();
+                let material: u32 = captured_pieces
+                    .iter()
+                    .map(|piece| get_piece_value(piece))
+                    .sum();
+                Line::raw(format!("{}: {} ({})", player, glyphs, material))
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(" Captured ")),
+            area,
+        );
+    }
+
+    // Shows the time left on each clock in a timed game, or else how long each player has taken
+    fn draw_clocks(&self, frame: &mut Frame, area: Rect) {
+        let current_player: Player = self.game.get_current_player();
+        let now: Instant = Instant::now();
+        let lines: Vec<Line> = [Player::White, Player::Black]
+            .iter()
+            .map(|player| {
+                let is_running: bool = match &self.game.clock {
+                    Some(clock) => clock.get_running_player() == Some(*player),
+                    None => *player == current_player && !self.game.status.is_finished(),
+                };
+                let time: Duration = match &self.game.clock {
+                    Some(clock) => clock.get_remaining_time(player, now),
+                    None if is_running => self.time_used[player] + (now - self.turn_started_at),
+                    None => self.time_used[player],
+                };
+                let style: Style = if is_running {
+                    Style::new().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::new()
+                };
+                Line::styled(format!("{}: {}", player, format_duration(time)), style)
+            })
+            .collect();
+        let title: String = match &self.game.clock {
+            Some(clock) => format!(" Clocks ({}) ", clock.time_control),
+            None => " Time used ".to_string(),
+        };
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(title)),
+            area,
+        );
+    }
+
+    fn draw_status_bar(&self, frame: &mut Frame, area: Rect) {
+        let player: Player = self.game.get_current_player();
+        let status: String = match &self.game.status {
+            GameStatus::Finished(result) => result.to_string(),
+            GameStatus::DrawClaimable(reason) => {
+                format!(
+                    "Player {} to move, and may claim a draw by {}",
+                    player, reason
+                )
+            }
+            GameStatus::Ongoing if self.game.board_data.is_in_check(&player) => {
+                format!("Player {} to move, and is in check!", player)
+            }
+            GameStatus::Ongoing => format!("Player {} to move", player),
+        };
+        let text: String = if self.message.is_empty() {
+            status
+        } else {
+            format!("{}. {}", status, self.message)
+        };
+        frame.render_widget(
+            Paragraph::new(text).block(Block::bordered().title(
+                " Arrows/click: pick a square, Enter: select, Esc: cancel, u: undo, r: redo, \
+                 f: flip, q: quit ",
+            )),
+            area,
+        );
+    }
+}
+
+// Pairs up the moves, e.g. "1. e4 e5", starting with "1... e5" if Black moved first
+fn get_move_list_lines(san_moves: &[String], first_turn: u32) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for (index, san) in san_moves.iter().enumerate() {
+        let turn: u32 = first_turn + index as u32;
+        let fullmove_number: u32 = turn / 2 + 1;
+        match (turn % 2, lines.last_mut()) {
+            (1, Some(line)) if index > 0 => {
+                line.push(' ');
+                line.push_str(san);
+            }
+            (1, _) => lines.push(format!("{}... {}", fullmove_number, san)),
+            _ => lines.push(format!("{}. {}", fullmove_number, san)),
+        }
+    }
+    lines
+}
+
+// The opposing pieces that `player` has captured, in the order they were captured
+fn get_captured_pieces<'a>(game: &'a ChessGame, player: &Player) -> Vec<&'a ChessPiece> {
+    game.move_history
+        .iter()
+        .filter(|history_entry| history_entry.chess_move.piece.get_player() == player)
+        .filter_map(|history_entry| history_entry.chess_move.captured_piece.as_ref())
+        .collect()
+}
+
+// The usual point values of the pieces, for comparing what each player has captured
+fn get_piece_value(piece: &ChessPiece) -> u32 {
+    match piece {
+        ChessPiece::Pawn(_) => 1,
+        ChessPiece::Knight(_) | ChessPiece::Bishop(_) => 3,
+        ChessPiece::Rook(_) => 5,
+        ChessPiece::Queen(_) => 9,
+        ChessPiece::King(_) => 0,
+    }
+}
+
+// Works out which square is drawn at the given terminal cell, if any
+fn get_square_at(
+    board: &ChessBoard,
+    board_area: Rect,
+    perspective: &Player,
+    x: u16,
+    y: u16,
+) -> Option<BoardPosition> {
+    let column: i32 = (x.checked_sub(board_area.x + ROW_LABEL_WIDTH)? / SQUARE_WIDTH) as i32;
+    let row: i32 = (y.checked_sub(board_area.y)? / SQUARE_HEIGHT) as i32;
+    let last_index: i32 = board.len() as i32 - 1;
+    let (row_index, column_index): (i32, i32) = match perspective {
+        Player::White => (last_index - row, column),
+        Player::Black => (row, last_index - column),
+    };
+    CandidateBoardPosition {
+        row_index,
+        column_index,
+    }
+    .validate_candidate_position(board)
+}
+
+#[cfg(test)]
+mod tests {
+    mod test_get_move_list_lines {
+        use crate::tui::get_move_list_lines;
+
+        fn san_moves(moves: &[&str]) -> Vec<String> {
+            moves.iter().map(|san| san.to_string()).collect()
+        }
+
+        #[test]
+        fn test_moves_are_paired() {
+            assert_eq!(
+                get_move_list_lines(&san_moves(&["e4", "e5", "Nf3"]), 0),
+                vec!["1. e4 e5", "2. Nf3"]
+            );
+        }
+
+        #[test]
+        fn test_black_moving_first() {
+            assert_eq!(
+                get_move_list_lines(&san_moves(&["e5", "Nf3", "Nc6"]), 1),
+                vec!["1... e5", "2. Nf3 Nc6"]
+            );
+        }
+    }
+
+    mod test_get_captured_pieces {
+        use crate::{
+            game::{board_position::get_position, ChessGame},
+            piece::ChessPiece,
+            player::Player,
+            tui::{get_captured_pieces, get_piece_value},
+        };
+
+        #[test]
+        fn test_captures_are_listed_by_the_capturing_player() {
+            let mut game: ChessGame = ChessGame::new();
+            for (from, to) in [("e2", "e4"), ("d7", "d5"), ("e4", "d5"), ("d8", "d5")] {
+                game.make_move(&get_position(from), &get_position(to))
+                    .unwrap();
+            }
+            let white_captures: Vec<&ChessPiece> = get_captured_pieces(&game, &Player::White);
+            let black_captures: Vec<&ChessPiece> = get_captured_pieces(&game, &Player::Black);
+            assert_eq!(white_captures.len(), 1);
+            assert_eq!(black_captures.len(), 1);
+            assert_eq!(get_piece_value(white_captures[0]), 1);
+            assert_eq!(black_captures[0].get_piece_letter(), 'P');
+        }
+    }
+
+    mod test_get_square_at {
+        use crate::{
+            game::board::{initialize_empty_board, ChessBoard},
+            player::Player,
+            tui::get_square_at,
+        };
+        use ratatui::layout::Rect;
+
+        #[test]
+        fn test_squares_from_both_perspectives() {
+            let board: ChessBoard = initialize_empty_board();
+            let board_area: Rect = Rect::new(1, 1, 42, 17);
+            // The top left square, just past the row labels
+            let top_left = |perspective: &Player| {
+                get_square_at(&board, board_area, perspective, 3, 1)
+                    .map(|position| position.to_string())
+            };
+            assert_eq!(top_left(&Player::White), Some("a8".to_string()));
+            assert_eq!(top_left(&Player::Black), Some("h1".to_string()));
+            // The bottom right square
+            assert_eq!(
+                get_square_at(&board, board_area, &Player::White, 42, 16)
+                    .map(|position| position.to_string()),
+                Some("h1".to_string())
+            );
+        }
+
+        #[test]
+        fn test_outside_the_board() {
+            let board: ChessBoard = initialize_empty_board();
+            let board_area: Rect = Rect::new(1, 1, 42, 17);
+            // The row labels, the column labels and past the right edge
+            assert_eq!(
+                get_square_at(&board, board_area, &Player::White, 1, 1),
+                None
+            );
+            assert_eq!(
+                get_square_at(&board, board_area, &Player::White, 3, 17),
+                None
+            );
+            assert_eq!(
+                get_square_at(&board, board_area, &Player::White, 43, 1),
+                None
+            );
+        }
+    }
+
+    mod test_san_moves {
+        use crate::{
+            game::{board_position::get_position, chess_move::LegalMove, ChessGame},
+            tui::FullScreenView,
+        };
+        use ratatui::crossterm::event::KeyCode;
+
+        #[test]
+        fn test_moves_are_kept_in_step_with_the_game() {
+            let mut game: ChessGame = ChessGame::new();
+            let mut view: FullScreenView = FullScreenView::new(&mut game);
+            for (from, to) in [("e2", "e4"), ("e7", "e5"), ("g1", "f3")] {
+                let legal_move: LegalMove = LegalMove {
+                    from: get_position(from),
+                    to: get_position(to),
+                    promotion: None,
+                };
+                view.play_move(legal_move);
+            }
+            assert_eq!(view.san_moves, vec!["e4", "e5", "Nf3"]);
+            view.handle_key(KeyCode::Char('u'));
+            assert_eq!(view.san_moves, vec!["e4", "e5"]);
+            view.handle_key(KeyCode::Char('r'));
+            assert_eq!(view.san_moves, view.game.get_san_move_list());
+        }
+    }
+
+    mod test_draw {
+        use crate::{game::ChessGame, tui::FullScreenView};
+        use ratatui::{backend::TestBackend, Terminal};
+
+        fn draw_screen(game: &mut ChessGame) -> String {
+            let mut view: FullScreenView = FullScreenView::new(game);
+            let mut terminal: Terminal<TestBackend> =
+                Terminal::new(TestBackend::new(90, 30)).unwrap();
+            terminal.draw(|frame| view.draw(frame)).unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect()
+        }
+
+        #[test]
+        fn test_all_panes_are_drawn() {
+            let screen: String = draw_screen(&mut ChessGame::new());
+            for text in [
+                " Board ",
+                " Moves ",
+                " Captured ",
+                " Time used ",
+                "Player White to move",
+            ] {
+                assert!(screen.contains(text), "{}", text);
+            }
+            assert!(screen.contains('♚'));
+        }
+
+        #[test]
+        fn test_clocks_are_drawn_in_a_timed_game() {
+            let mut game: ChessGame = ChessGame::new();
+            game.start_clock("5+3".parse().unwrap());
+            let screen: String = draw_screen(&mut game);
+            assert!(screen.contains(" Clocks (5+3) "));
+            // Only White's clock is running
+            assert!(screen.contains("Black: 05:00"));
+        }
+    }
+}